@@ -1,6 +1,6 @@
 //! Implementations for [Arbitrary] for non-empty container types.
 
-use crate::{NESet, NEVec};
+use crate::{NESet, NESortedVec, NEString, NEVec};
 use arbitrary::{Arbitrary, Unstructured};
 use std::collections::HashSet;
 use std::hash::Hash;
@@ -22,3 +22,17 @@ impl<'a, T: Arbitrary<'a> + Eq + Hash> Arbitrary<'a> for NESet<T> {
         Ok(Self::__from_set_unsafe(tail))
     }
 }
+
+impl<'a, T: Arbitrary<'a> + Ord> Arbitrary<'a> for NESortedVec<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(NEVec::<T>::arbitrary(u)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for NEString {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let head = char::arbitrary(u)?;
+        let tail = String::arbitrary(u)?;
+        Ok(Self::new(head, &tail))
+    }
+}
@@ -1,11 +1,81 @@
 //! Error types for the non-empty types.
 
+use std::fmt;
+
 /// Errors that can occur when working with non-empty types..
+///
+/// This enum is `#[non_exhaustive]`, so new variants may be added as more operations land.
+/// Downstream `match` expressions must therefore include a wildcard arm:
+///
+/// ```rust
+/// # use nonempty_containers::{nev, NonEmptyError};
+/// #
+/// let mut nev = nev![1, 2];
+/// let error = nev.try_push_front_capped(0, 2).unwrap_err();
+/// let message = match error {
+///     NonEmptyError::CapacityExceeded => "full",
+///     NonEmptyError::Empty => "empty",
+///     _ => "other",
+/// };
+/// assert_eq!(message, "full");
+/// assert_eq!(error.to_string(), "the collection is already at capacity");
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum NonEmptyError {
     /// Encountered an empty collection when it was expected to be non-empty.
     Empty,
 
-    /// Attempted to remove an element from a singleton [NonEmptyVec].
+    /// Attempted to remove an element from a singleton [NEVec](crate::NEVec) or
+    /// [NESet](crate::NESet).
     AlreadySingleton,
+
+    /// Encountered collections of differing lengths where equal lengths were expected.
+    LengthMismatch,
+
+    /// Attempted to grow a collection that is already at its capacity.
+    CapacityExceeded,
+
+    /// Encountered a collection with fewer elements than required.
+    TooShort {
+        /// The minimum number of elements required.
+        needed: usize,
+        /// The number of elements found.
+        got: usize,
+    },
+
+    /// Attempted to access a position past the end of a collection.
+    OutOfBounds {
+        /// The offending index. For a range, this is its exclusive end.
+        index: usize,
+        /// The length of the collection.
+        len: usize,
+    },
+}
+
+impl fmt::Display for NonEmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NonEmptyError::Empty => {
+                write!(f, "expected a non-empty collection, found an empty one")
+            }
+            NonEmptyError::AlreadySingleton => {
+                write!(
+                    f,
+                    "cannot remove the only element of a non-empty collection"
+                )
+            }
+            NonEmptyError::LengthMismatch => write!(f, "expected collections of equal length"),
+            NonEmptyError::CapacityExceeded => write!(f, "the collection is already at capacity"),
+            NonEmptyError::TooShort { needed, got } => {
+                write!(f, "expected at least {needed} elements, found {got}")
+            }
+            NonEmptyError::OutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "index {index} is out of bounds for a collection of length {len}"
+                )
+            }
+        }
+    }
 }
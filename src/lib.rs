@@ -2,7 +2,7 @@
 //!
 //! Non-emptiness is generally a very useful tool, when you need inherent guarantees in code but
 //! want to avoid repeatedly writing the same checks. This module provides non-empty versions of
-//! common container types, such as [Vec].
+//! common container types, such as [Vec] and [String].
 
 pub mod ne_vec;
 pub use ne_vec::NEVec;
@@ -13,9 +13,23 @@ pub use ne_set::NESet;
 pub mod ne_ordered_set;
 pub use ne_ordered_set::NEOrderedSet;
 
+pub mod ne_sorted_vec;
+pub use ne_sorted_vec::NESortedVec;
+
+pub mod ne_slice;
+pub use ne_slice::NESlice;
+
+pub mod ne_string;
+pub use ne_string::NEString;
+
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
 
+#[cfg(feature = "serde")]
+mod serde;
+
 #[macro_use]
 mod macros;
+
 mod errors;
+pub use errors::NonEmptyError;
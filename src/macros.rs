@@ -64,3 +64,29 @@ macro_rules! neos {
         $crate::NEOrderedSet::new($head, vec![$($tail),+])
     );
 }
+
+/// Creates a [NEString](crate::NEString) from a string literal, or from a head character and a
+/// tail string slice. An empty literal is rejected at compile time.
+///
+/// ```
+/// # use nonempty_containers::{nestr, NEString};
+/// #
+/// assert_eq!(nestr!("abc").as_str(), "abc");
+/// assert_eq!(nestr!('a'; "bc"), NEString::new('a', "bc"));
+/// ```
+///
+/// ```compile_fail
+/// # use nonempty_containers::nestr;
+/// #
+/// let empty = nestr!("");
+/// ```
+#[macro_export]
+macro_rules! nestr {
+    ($head:expr; $tail:expr) => {
+        $crate::NEString::new($head, $tail)
+    };
+    ($literal:literal) => {{
+        const _: () = assert!(!$literal.is_empty(), "nestr! requires a non-empty literal");
+        $crate::NEString::__from_string_unsafe(String::from($literal))
+    }};
+}
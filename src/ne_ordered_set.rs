@@ -33,6 +33,29 @@ impl<T: Ord> NEOrderedSet<T> {
         }
     }
 
+    /// Attempts to create a non-empty ordered set from a [Vec] that is already sorted in
+    /// ascending order, which lets the set be built in linear time. Duplicates are allowed and
+    /// collapsed. Returns an error if the provided vector is empty.
+    ///
+    /// The input must be sorted. This is only checked in debug builds; unsorted input still
+    /// yields a valid set, just without the speed-up.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::NEOrderedSet;
+    /// #
+    /// let set = NEOrderedSet::from_sorted_vec(vec![1, 2, 2, 3]).unwrap();
+    /// assert_eq!(set, NEOrderedSet::new(1, vec![2, 3]));
+    /// assert!(NEOrderedSet::<u32>::from_sorted_vec(Vec::new()).is_err());
+    /// ```
+    pub fn from_sorted_vec(vec: Vec<T>) -> Result<Self, NonEmptyError> {
+        debug_assert!(vec.windows(2).all(|pair| pair[0] <= pair[1]));
+        if vec.is_empty() {
+            Err(Empty)
+        } else {
+            Ok(Self(vec.into_iter().collect()))
+        }
+    }
+
     /// Hidden constructor used internally by macros.
     #[doc(hidden)]
     pub fn __from_set_unsafe(set: BTreeSet<T>) -> Self {
@@ -73,6 +96,100 @@ impl<T: Ord> NEOrderedSet<T> {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Consumes the set, mapping each element and rebuilding it in the order of the new type.
+    /// The result may be smaller if `f` maps distinct elements to equal ones, but is never empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::neos;
+    /// #
+    /// let negated = neos![1, 2, 3].map(|x| -x);
+    /// assert!(negated.into_iter().eq([-3, -2, -1]));
+    /// ```
+    pub fn map<U: Ord, F: FnMut(T) -> U>(self, f: F) -> NEOrderedSet<U> {
+        NEOrderedSet(self.0.into_iter().map(f).collect())
+    }
+
+    /// Returns true if every element of this set is also in `other`.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::neos;
+    /// #
+    /// assert!(neos![2, 3].is_subset(&neos![1, 2, 3]));
+    /// assert!(!neos![1, 2, 3].is_subset(&neos![2, 3, 4]));
+    /// ```
+    pub fn is_subset(&self, other: &NEOrderedSet<T>) -> bool {
+        self.0.is_subset(&other.0)
+    }
+
+    /// Returns true if this set has no elements in common with `other`.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::neos;
+    /// #
+    /// assert!(neos![1, 2].is_disjoint(&neos![3, 4]));
+    /// assert!(!neos![1, 2, 3].is_disjoint(&neos![2, 3, 4]));
+    /// ```
+    pub fn is_disjoint(&self, other: &NEOrderedSet<T>) -> bool {
+        self.0.is_disjoint(&other.0)
+    }
+
+    /// Returns the `n`-th smallest element, if any. `nth(0)` always returns the minimum.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::neos;
+    /// #
+    /// let set = neos![10, 20, 30];
+    /// assert_eq!(set.nth(0), Some(&10));
+    /// assert_eq!(set.nth(1), Some(&20));
+    /// assert_eq!(set.nth(3), None);
+    /// ```
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        self.0.iter().nth(n)
+    }
+
+    /// Returns the sorted position of a value, or None if the set does not contain it.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::neos;
+    /// #
+    /// let set = neos![10, 20, 30];
+    /// assert_eq!(set.rank(&30), Some(2));
+    /// assert_eq!(set.rank(&25), None);
+    /// ```
+    pub fn rank(&self, value: &T) -> Option<usize> {
+        self.0
+            .contains(value)
+            .then(|| self.0.range(..value).count())
+    }
+}
+
+impl<T: Ord + Clone> NEOrderedSet<T> {
+    /// Returns the union of two sets, which is always non-empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::neos;
+    /// #
+    /// let union = neos![1, 2, 3].union(&neos![2, 3, 4]);
+    /// assert_eq!(union, neos![1, 2, 3, 4]);
+    /// ```
+    pub fn union(&self, other: &NEOrderedSet<T>) -> NEOrderedSet<T> {
+        Self(self.0.union(&other.0).cloned().collect())
+    }
+
+    /// Returns the intersection of two sets, which may be empty.
+    ///
+    /// ```rust
+    /// # use std::collections::BTreeSet;
+    /// # use nonempty_containers::neos;
+    /// #
+    /// let intersection = neos![1, 2, 3].intersection(&neos![2, 3, 4]);
+    /// assert_eq!(intersection, BTreeSet::from([2, 3]));
+    /// assert!(neos![1].intersection(&neos![2]).is_empty());
+    /// ```
+    pub fn intersection(&self, other: &NEOrderedSet<T>) -> BTreeSet<T> {
+        self.0.intersection(&other.0).cloned().collect()
+    }
 }
 
 impl<T: Ord> From<NEOrderedSet<T>> for BTreeSet<T> {
@@ -22,12 +22,18 @@
 //! ```
 //!
 //! When the feature `arbitrary` is enabled, [NESet] implements [Arbitrary]
-//! for generation of randomly populated instances.
+//! for generation of randomly populated instances. When the feature `rand` is enabled, [NESet]
+//! can be sampled from.
 
 use crate::errors::NonEmptyError;
+use crate::NEVec;
+#[cfg(feature = "rand")]
+use rand::Rng;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::hash_set::{IntoIter, Iter};
 use std::collections::HashSet;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 
 /// Non-empty set type.
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -55,6 +61,22 @@ impl<T: Eq + Hash> NESet<T> {
         Self(set)
     }
 
+    /// Creates a new [NESet] from a head element and an iterator of further elements. This is
+    /// infallible as the head guarantees non-emptiness.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nes, NESet};
+    /// #
+    /// assert_eq!(NESet::from_head_iter(1, Vec::new()), nes![1]);
+    /// assert_eq!(NESet::from_head_iter(1, 1..4), nes![1, 2, 3]);
+    /// ```
+    pub fn from_head_iter<I: IntoIterator<Item = T>>(head: T, rest: I) -> Self {
+        let mut set = HashSet::new();
+        set.insert(head);
+        set.extend(rest);
+        Self(set)
+    }
+
     /// Creates a new [NESet] from a [HashSet]. Returns an error if the set is empty.
     pub fn from(set: HashSet<T>) -> Result<Self, NonEmptyError> {
         match set.is_empty() {
@@ -91,6 +113,23 @@ impl<T: Eq + Hash> NESet<T> {
         self.0.insert(value)
     }
 
+    /// Adds every element of an iterator to the set, returning how many were not already
+    /// present.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nes;
+    /// #
+    /// let mut nes = nes![1, 2];
+    /// assert_eq!(nes.insert_many(vec![2, 2, 3, 4]), 2);
+    /// assert_eq!(nes, nes![1, 2, 3, 4]);
+    /// ```
+    pub fn insert_many<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        iter.into_iter()
+            .map(|value| self.0.insert(value))
+            .filter(|&inserted| inserted)
+            .count()
+    }
+
     /// Removes an element from the set. Returns `true` if the element was present.
     pub fn remove(&mut self, value: &T) -> bool {
         self.0.remove(value)
@@ -100,6 +139,185 @@ impl<T: Eq + Hash> NESet<T> {
     pub fn contains(&self, value: &T) -> bool {
         self.0.contains(value)
     }
+
+    /// Removes every element of `other` from the set. Returns an error and leaves the set
+    /// untouched if that would remove every element.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nes;
+    /// #
+    /// let mut nes = nes![1, 2, 3];
+    /// assert!(nes.difference_assign(&nes![2, 3]).is_ok());
+    /// assert_eq!(nes, nes![1]);
+    ///
+    /// let mut nes = nes![1, 2, 3];
+    /// assert!(nes.difference_assign(&nes![1, 2, 3]).is_err());
+    /// assert_eq!(nes, nes![1, 2, 3]);
+    /// ```
+    pub fn difference_assign(&mut self, other: &NESet<T>) -> Result<(), NonEmptyError> {
+        if self.0.is_subset(&other.0) {
+            return Err(NonEmptyError::Empty);
+        }
+        self.0.retain(|value| !other.0.contains(value));
+        Ok(())
+    }
+
+    /// Removes `value` if it is present, otherwise inserts it. Returns whether `value` is in the
+    /// set afterwards, or an error if removing it would leave the set empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nes;
+    /// #
+    /// let mut nes = nes![1, 2];
+    /// assert!(!nes.toggle(2).unwrap());
+    /// assert!(nes.toggle(3).unwrap());
+    /// assert_eq!(nes, nes![1, 3]);
+    ///
+    /// let mut singleton = nes![1];
+    /// assert!(singleton.toggle(1).is_err());
+    /// assert_eq!(singleton, nes![1]);
+    /// ```
+    pub fn toggle(&mut self, value: T) -> Result<bool, NonEmptyError> {
+        if !self.0.contains(&value) {
+            self.0.insert(value);
+            return Ok(true);
+        }
+        match self.0.len() {
+            1 => Err(NonEmptyError::AlreadySingleton),
+            _ => {
+                self.0.remove(&value);
+                Ok(false)
+            }
+        }
+    }
+
+    /// Removes all but one arbitrary element, leaving a singleton [NESet].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nes;
+    /// #
+    /// let original = nes![1, 2, 3];
+    /// let mut nes = original.clone();
+    /// nes.clear_keeping_one();
+    /// assert_eq!(nes.len(), 1);
+    /// assert!(nes.into_iter().all(|x| original.contains(&x)));
+    /// ```
+    pub fn clear_keeping_one(&mut self) {
+        let mut first = true;
+        self.0.retain(|_| std::mem::replace(&mut first, false));
+    }
+
+    /// Consumes the [NESet], mapping each element and rebuilding the set. The result may be
+    /// smaller if `f` maps distinct elements to equal ones, but is never empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nes;
+    /// #
+    /// assert_eq!(nes![1, 2, 3].map(|x| x % 2), nes![0, 1]);
+    /// ```
+    pub fn map<U: Eq + Hash, F: FnMut(T) -> U>(self, f: F) -> NESet<U> {
+        NESet(self.0.into_iter().map(f).collect())
+    }
+
+    /// Consumes the [NESet], returning its elements as a sorted [NEVec]. Unlike iterating the
+    /// set directly, this gives a deterministic order.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nes, nev};
+    /// #
+    /// assert_eq!(nes![3, 1, 2].into_sorted_vec(), nev![1, 2, 3]);
+    /// ```
+    pub fn into_sorted_vec(self) -> NEVec<T>
+    where
+        T: Ord,
+    {
+        let mut vec = Vec::from_iter(self.0);
+        vec.sort_unstable();
+        NEVec::__from_vec_unsafe(vec)
+    }
+
+    /// Consumes the [NESet], returning its elements as a [NEVec] sorted by `compare`. Elements
+    /// that `compare` considers equal end up in an unspecified order.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nes, nev};
+    /// #
+    /// assert_eq!(nes![3, 1, 2].into_sorted_vec_by(|a, b| b.cmp(a)), nev![3, 2, 1]);
+    /// ```
+    pub fn into_sorted_vec_by<F: FnMut(&T, &T) -> Ordering>(self, compare: F) -> NEVec<T> {
+        let mut vec = Vec::from_iter(self.0);
+        vec.sort_unstable_by(compare);
+        NEVec::__from_vec_unsafe(vec)
+    }
+
+    /// Returns an iterator over the elements of the [NESet] in ascending order.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nes;
+    /// #
+    /// let nes = nes![3, 1, 2];
+    /// assert!(nes.sorted_iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn sorted_iter(&self) -> impl Iterator<Item = &T>
+    where
+        T: Ord,
+    {
+        let mut vec = Vec::from_iter(&self.0);
+        vec.sort_unstable();
+        vec.into_iter()
+    }
+
+    /// Returns a reference to a uniformly random element. This cannot fail as the invariant
+    /// guarantees at least one element is present. This is only available when the `rand`
+    /// feature is enabled.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nes;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// #
+    /// let nes = nes![1, 2, 3, 4, 5];
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// for _ in 0..100 {
+    ///     assert!(nes.contains(nes.choose(&mut rng)));
+    /// }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn choose<R: Rng>(&self, rng: &mut R) -> &T {
+        let index = rng.random_range(0..self.0.len());
+        self.0
+            .iter()
+            .nth(index)
+            .expect("[NESet] invariant violated.")
+    }
+}
+
+/// Hashes the elements independently of iteration order, so equal sets hash equally however they
+/// were built. Each element is hashed on its own and the results are combined with a wrapping
+/// sum. The resulting values are only stable within a single build of the program.
+///
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use nonempty_containers::nes;
+/// #
+/// let mut built = nes![3];
+/// built.insert(2);
+/// built.insert(1);
+///
+/// let mut sets = HashSet::new();
+/// sets.insert(nes![1, 2, 3]);
+/// sets.insert(built);
+/// assert_eq!(sets.len(), 1);
+/// ```
+impl<T: Eq + Hash> Hash for NESet<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let combined = self.0.iter().fold(0u64, |combined, value| {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            combined.wrapping_add(hasher.finish())
+        });
+        state.write_usize(self.0.len());
+        state.write_u64(combined);
+    }
 }
 
 impl<T: Eq + Hash> From<NESet<T>> for HashSet<T> {
@@ -0,0 +1,109 @@
+//! A borrowed non-empty slice type that guarantees at least one element is present. [NESlice] is
+//! a view into existing storage, such as a window of a [NEVec](crate::NEVec), so it never
+//! allocates. Get started with:
+//!
+//! ```rust, no_run
+//! # use nonempty_containers::{nev, NESlice};
+//! #
+//! let slice = NESlice::from_slice(&[1, 2, 3]).unwrap();
+//! let windows = nev![1, 2, 3].windows_ne(2).count();
+//! ```
+
+use crate::errors::NonEmptyError;
+use std::ops::Deref;
+use std::slice::Iter;
+
+/// Borrowed non-empty slice type.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct NESlice<'a, T>(&'a [T]);
+
+impl<'a, T> NESlice<'a, T> {
+    /// Attempts to create a [NESlice] from a slice. Returns an error if the slice is empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::NESlice;
+    /// #
+    /// assert!(NESlice::from_slice(&[42]).is_ok());
+    /// assert!(NESlice::<u32>::from_slice(&[]).is_err());
+    /// ```
+    pub fn from_slice(slice: &'a [T]) -> Result<Self, NonEmptyError> {
+        match slice.is_empty() {
+            true => Err(NonEmptyError::Empty),
+            false => Ok(Self(slice)),
+        }
+    }
+
+    /// Creates a new [NESlice] from a slice without checking if it's empty. This operation is
+    /// unsafe and should only be used within this crate!
+    #[doc(hidden)]
+    pub fn __from_slice_unsafe(slice: &'a [T]) -> Self {
+        debug_assert!(!slice.is_empty());
+        Self(slice)
+    }
+
+    /// Returns the first element. This operation is safe as the invariant guarantees at least one
+    /// element is present.
+    pub fn head(&self) -> &'a T {
+        self.0.first().expect("[NESlice] invariant violated.")
+    }
+
+    /// Returns the last element. This operation is safe as the invariant guarantees at least one
+    /// element is present.
+    pub fn last(&self) -> &'a T {
+        self.0.last().expect("[NESlice] invariant violated.")
+    }
+
+    /// Returns the length of this [NESlice].
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// A [NESlice] is always non-empty.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the underlying slice.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.0
+    }
+
+    /// Returns an iterator over the elements of the [NESlice].
+    pub fn iter(&self) -> Iter<'a, T> {
+        self.0.iter()
+    }
+}
+
+// Implemented by hand, as deriving would needlessly require `T: Clone` for a shared reference.
+impl<T> Clone for NESlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NESlice<'_, T> {}
+
+impl<'a, T> TryFrom<&'a [T]> for NESlice<'a, T> {
+    type Error = NonEmptyError;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        NESlice::from_slice(slice)
+    }
+}
+
+impl<'a, T> IntoIterator for NESlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> Deref for NESlice<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
@@ -0,0 +1,172 @@
+//! A non-empty vector type that keeps its elements in ascending order. [NESortedVec] has an
+//! interface similar to [NEVec], but maintains sortedness across insertions so that membership
+//! tests are O(log n) rather than O(n). Get started with:
+//!
+//! ```rust, no_run
+//! # use nonempty_containers::{nev, NESortedVec};
+//! #
+//! let sorted = NESortedVec::new(42, vec![3, 1, 2]);
+//! let singleton = NESortedVec::singleton(42);
+//! let converted = NESortedVec::from(nev![3, 1, 2]);
+//! ```
+
+use crate::errors::NonEmptyError;
+use crate::NEVec;
+use std::ops::Deref;
+use std::slice::Iter;
+use std::vec::IntoIter;
+
+/// Non-empty vector type whose elements are kept sorted.
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct NESortedVec<T: Ord>(Vec<T>);
+
+impl<T: Ord> NESortedVec<T> {
+    /// Creates a new [NESortedVec], sorting the given elements.
+    pub fn new(head: T, tail: Vec<T>) -> Self {
+        Self::from(NEVec::new(head, tail))
+    }
+
+    /// Creates a new singleton [NESortedVec].
+    pub fn singleton(value: T) -> Self {
+        Self(vec![value])
+    }
+
+    /// Attempts to create a [NESortedVec] from a [Vec], sorting it. Returns an error if the
+    /// [Vec] is empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::NESortedVec;
+    /// #
+    /// assert!(NESortedVec::from_vec(vec![2, 1]).is_ok());
+    /// assert!(NESortedVec::from_vec(Vec::<u32>::new()).is_err());
+    /// ```
+    pub fn from_vec(mut vec: Vec<T>) -> Result<Self, NonEmptyError> {
+        match vec.is_empty() {
+            true => Err(NonEmptyError::Empty),
+            false => {
+                vec.sort();
+                Ok(Self(vec))
+            }
+        }
+    }
+
+    /// Extracts the elements as a sorted [NEVec]. This operation is zero-cost.
+    pub fn into_vec(self) -> NEVec<T> {
+        NEVec::__from_vec_unsafe(self.0)
+    }
+
+    /// Returns the length of this [NESortedVec].
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// A [NESortedVec] is always non-empty.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the smallest element.
+    pub fn head(&self) -> &T {
+        self.0.first().expect("[NESortedVec] invariant violated.")
+    }
+
+    /// Returns the largest element.
+    pub fn last(&self) -> &T {
+        self.0.last().expect("[NESortedVec] invariant violated.")
+    }
+
+    /// Returns `true` if the [NESortedVec] contains `value`. This is a binary search, so it runs
+    /// in O(log n).
+    ///
+    /// ```rust
+    /// # use nonempty_containers::NESortedVec;
+    /// #
+    /// let sorted = NESortedVec::new(5, vec![3, 9, 1]);
+    /// assert!(sorted.contains(&9));
+    /// assert!(!sorted.contains(&4));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.binary_search(value).is_ok()
+    }
+
+    /// Inserts an element at its sorted position, returning the index it was inserted at. Equal
+    /// elements are inserted after existing ones.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::NESortedVec;
+    /// #
+    /// let mut sorted = NESortedVec::new(1, vec![4, 2]);
+    /// assert_eq!(sorted.insert(3), 2);
+    /// assert!(sorted.iter().eq(&[1, 2, 3, 4]));
+    /// ```
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.0.partition_point(|x| *x <= value);
+        self.0.insert(index, value);
+        index
+    }
+
+    /// Returns an iterator over the elements in ascending order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T: Ord> From<NEVec<T>> for NESortedVec<T> {
+    fn from(ne: NEVec<T>) -> Self {
+        let mut vec = Vec::from(ne);
+        vec.sort();
+        Self(vec)
+    }
+}
+
+impl<T: Ord> From<NESortedVec<T>> for NEVec<T> {
+    fn from(sorted: NESortedVec<T>) -> Self {
+        sorted.into_vec()
+    }
+}
+
+impl<T: Ord> From<NESortedVec<T>> for Vec<T> {
+    fn from(sorted: NESortedVec<T>) -> Self {
+        sorted.0
+    }
+}
+
+impl<T: Ord> TryFrom<Vec<T>> for NESortedVec<T> {
+    type Error = NonEmptyError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        NESortedVec::from_vec(vec)
+    }
+}
+
+impl<T: Ord> From<T> for NESortedVec<T> {
+    fn from(value: T) -> Self {
+        Self::singleton(value)
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a NESortedVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: Ord> IntoIterator for NESortedVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: Ord> Deref for NESortedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,151 @@
+//! A non-empty string type that guarantees at least one character is present. [NEString] has an
+//! interface similar to [String] with additional methods to enforce the invariant. Get started
+//! with:
+//!
+//! ```rust, no_run
+//! # use nonempty_containers::{nestr, NEString};
+//! #
+//! let nestr = NEString::new('h', "ello");
+//! let singleton = NEString::singleton('h');
+//! let r#macro = nestr!("hello");
+//! ```
+//!
+//! [NEString] dereferences to [str], so read-only string operations work as usual:
+//!
+//! ```rust, no_run
+//! # use nonempty_containers::nestr;
+//! #
+//! let nestr = nestr!("hello");
+//! assert!(nestr.starts_with("he"));
+//! assert_eq!(nestr.to_uppercase(), "HELLO");
+//! ```
+
+use crate::errors::NonEmptyError;
+use std::fmt;
+use std::ops::Deref;
+
+/// Non-empty UTF-8 string type.
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct NEString(String);
+
+impl NEString {
+    /// Creates a new [NEString], ensuring at least one character is present.
+    pub fn new(head: char, tail: &str) -> Self {
+        let mut string = String::with_capacity(head.len_utf8() + tail.len());
+        string.push(head);
+        string.push_str(tail);
+        Self(string)
+    }
+
+    /// Creates a new [NEString] holding a single character.
+    pub fn singleton(value: char) -> Self {
+        Self(value.to_string())
+    }
+
+    /// Attempts to create a [NEString] from a [String]. Returns an error if the [String] is empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::NEString;
+    /// #
+    /// let nestr = NEString::from_string("hello".to_string()).unwrap();
+    /// assert_eq!(nestr.into_string(), "hello");
+    /// assert!(NEString::from_string(String::new()).is_err());
+    /// ```
+    pub fn from_string(string: String) -> Result<Self, NonEmptyError> {
+        match string.is_empty() {
+            true => Err(NonEmptyError::Empty),
+            false => Ok(Self(string)),
+        }
+    }
+
+    /// Creates a new [NEString] from a [String] without checking if it's empty. This operation is
+    /// unsafe and should only be used by macros in this crate!
+    #[doc(hidden)]
+    pub fn __from_string_unsafe(string: String) -> Self {
+        debug_assert!(!string.is_empty());
+        Self(string)
+    }
+
+    /// Extracts the underlying [String]. This operation is zero-cost.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Returns the underlying string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the first character. This operation is safe as the invariant guarantees at least
+    /// one character is present.
+    pub fn first_char(&self) -> char {
+        self.0
+            .chars()
+            .next()
+            .expect("[NEString] invariant violated.")
+    }
+
+    /// Appends a character to the end of the string.
+    pub fn push(&mut self, value: char) {
+        self.0.push(value);
+    }
+
+    /// Appends a string slice to the end of the string. Appending an empty slice is a no-op.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nestr;
+    /// #
+    /// let mut nestr = nestr!("ab");
+    /// nestr.push_str("");
+    /// nestr.push('c');
+    /// nestr.push_str("de");
+    /// assert_eq!(nestr.as_str(), "abcde");
+    /// ```
+    pub fn push_str(&mut self, value: &str) {
+        self.0.push_str(value);
+    }
+
+    /// Returns the length of this [NEString] in bytes, like [String::len].
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// A [NEString] is always non-empty.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl From<NEString> for String {
+    fn from(nestr: NEString) -> Self {
+        nestr.0
+    }
+}
+
+impl TryFrom<String> for NEString {
+    type Error = NonEmptyError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        NEString::from_string(string)
+    }
+}
+
+impl From<char> for NEString {
+    fn from(value: char) -> Self {
+        Self::singleton(value)
+    }
+}
+
+impl fmt::Display for NEString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Deref for NEString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -10,7 +10,8 @@
 //! ```
 //!
 //! [NEVec] conforms to [Index], [IntoIterator], [Deref], and many more, so operations are
-//! as [Vec]-like as possible. They are also usually zero-cost.
+//! as [Vec]-like as possible. They are also usually zero-cost. [NEVec] is backed by a [Vec], so
+//! slice methods are available directly through [Deref].
 //!
 //! ```rust, no_run
 //! # use nonempty_containers::nev;
@@ -22,26 +23,38 @@
 //! ```
 //!
 //! When the feature `arbitrary` is enabled, [NEVec] implements [Arbitrary]
-//! for generation of randomly populated instances.
+//! for generation of randomly populated instances. When the feature `rand` is enabled, [NEVec]
+//! can be shuffled in place and sampled from. When the feature `rayon` is enabled, [NEVec] can be
+//! mapped in parallel. When the feature `serde` is enabled, [NEVec] serializes exactly like a
+//! [Vec], and rejects empty sequences when deserializing.
 
 use crate::errors::NonEmptyError;
+use crate::{NEOrderedSet, NESlice};
 #[cfg(feature = "im")]
 use im::Vector;
-use std::collections::vec_deque::IntoIter;
-use std::collections::vec_deque::{Iter, IterMut};
-use std::collections::VecDeque;
-use std::ops::Index;
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::ops::{Deref, Index, IndexMut, Range};
+use std::slice::{Chunks, Iter, IterMut, SliceIndex};
+use std::vec::IntoIter;
 
 /// Non-empty vector type.
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
-pub struct NEVec<T>(VecDeque<T>);
+pub struct NEVec<T>(Vec<T>);
 
 impl<T> NEVec<T> {
     /// Creates a new [NEVec], ensuring at least one element is present.
     pub fn new(head: T, tail: Vec<T>) -> Self {
         // We can afford to call [Vec::len()] here because it's O(1).
-        let mut vec = VecDeque::from(tail);
-        vec.push_front(head);
+        let mut vec = Vec::with_capacity(1 + tail.len());
+        vec.push(head);
+        vec.extend(tail);
         Self(vec)
     }
 
@@ -53,33 +66,144 @@ impl<T> NEVec<T> {
     /// NEVec::new(value, Vec::new());
     /// ```
     pub fn singleton(value: T) -> Self {
-        let mut vec = VecDeque::new();
-        vec.push_back(value);
+        Self(vec![value])
+    }
+
+    /// Creates a new [NEVec] from a head element and a cloned tail slice. Semantically
+    /// equivalent to `NEVec::new(head, tail.to_vec())`.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nev, NEVec};
+    /// #
+    /// assert_eq!(NEVec::from_head_and_slice(1, &[2, 3]), nev![1, 2, 3]);
+    /// ```
+    pub fn from_head_and_slice(head: T, tail: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(1 + tail.len());
+        vec.push(head);
+        vec.extend_from_slice(tail);
         Self(vec)
     }
 
+    /// Creates a new [NEVec] holding `n` clones of `value`. The [NonZeroUsize] count guarantees
+    /// the result is non-empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nev, NEVec};
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// let n = NonZeroUsize::new(3).unwrap();
+    /// assert_eq!(NEVec::repeat_nonzero('x', n), nev!['x', 'x', 'x']);
+    /// ```
+    pub fn repeat_nonzero(value: T, n: NonZeroUsize) -> Self
+    where
+        T: Clone,
+    {
+        Self(vec![value; n.get()])
+    }
+
     /// Returns the first element. This operation is safe as the invariant guarantees at least one
     /// element is present.
     pub fn head(&self) -> &T {
-        self.0.front().expect("[NonEmptyVec] invariant violated.")
+        self.0.first().expect("[NEVec] invariant violated.")
     }
 
     /// Returns all elements except the last one. This may be empty if the [NEVec] is a
     /// singleton.
     pub fn init(&self) -> Iter<'_, T> {
-        self.0.range(..self.0.len() - 1)
+        self.0[..self.0.len() - 1].iter()
     }
 
     /// Returns all elements except the first one. This may be empty if the [NEVec] is a
     /// singleton.
     pub fn tail(&self) -> Iter<'_, T> {
-        self.0.range(1..self.0.len())
+        self.0[1..].iter()
     }
 
     /// Returns the last element. This operation is safe as the invariant guarantees at least one
     /// element is present.
     pub fn last(&self) -> &T {
-        self.0.back().expect("[NonEmptyVec] invariant violated.")
+        self.0.last().expect("[NEVec] invariant violated.")
+    }
+
+    /// Returns the element at a signed index, where negative indices count from the back (`-1`
+    /// is the last element). Indices `0` and `-1` always return [Some].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3];
+    /// assert_eq!(nev.get_signed(0), Some(&1));
+    /// assert_eq!(nev.get_signed(-1), Some(&3));
+    /// assert_eq!(nev.get_signed(-3), Some(&1));
+    /// assert_eq!(nev.get_signed(-4), None);
+    /// assert_eq!(nev.get_signed(3), None);
+    /// ```
+    pub fn get_signed(&self, index: isize) -> Option<&T> {
+        let index = match index < 0 {
+            true => self.0.len().checked_sub(index.unsigned_abs())?,
+            false => index as usize,
+        };
+        self.0.get(index)
+    }
+
+    /// Replaces the first element, returning the previous one.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3];
+    /// assert_eq!(nev.replace_head(9), 1);
+    /// assert_eq!(nev, nev![9, 2, 3]);
+    /// ```
+    pub fn replace_head(&mut self, value: T) -> T {
+        let head = self.0.first_mut().expect("[NEVec] invariant violated.");
+        std::mem::replace(head, value)
+    }
+
+    /// Consumes the [NEVec], returning it with `f` applied to the first element.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3].update_head(|x| x * 10), nev![10, 2, 3]);
+    /// ```
+    pub fn update_head<F: FnOnce(T) -> T>(self, f: F) -> NEVec<T> {
+        let mut vec = self.0;
+        let head = vec.remove(0);
+        vec.insert(0, f(head));
+        Self(vec)
+    }
+
+    /// Replaces the last element, returning the previous one.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3];
+    /// assert_eq!(nev.replace_last(9), 3);
+    /// assert_eq!(nev, nev![1, 2, 9]);
+    /// ```
+    pub fn replace_last(&mut self, value: T) -> T {
+        let last = self.0.last_mut().expect("[NEVec] invariant violated.");
+        std::mem::replace(last, value)
+    }
+
+    /// Swaps the first and last elements, leaving the rest in place. This is a no-op for a
+    /// singleton.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3, 4];
+    /// nev.swap_ends();
+    /// assert_eq!(nev, nev![4, 2, 3, 1]);
+    /// ```
+    pub fn swap_ends(&mut self) {
+        let last = self.0.len() - 1;
+        self.0.swap(0, last);
     }
 
     /// Attempts to create a [NEVec] from a [Vec], returning [None] if the [Vec] is empty.
@@ -92,7 +216,7 @@ impl<T> NEVec<T> {
     pub fn from_vec(vec: Vec<T>) -> Result<Self, NonEmptyError> {
         match vec.is_empty() {
             true => Err(NonEmptyError::Empty),
-            false => Ok(Self(VecDeque::from(vec))),
+            false => Ok(Self(vec)),
         }
     }
 
@@ -109,7 +233,49 @@ impl<T> NEVec<T> {
     pub fn from_deque(deque: VecDeque<T>) -> Result<Self, NonEmptyError> {
         match deque.is_empty() {
             true => Err(NonEmptyError::Empty),
-            false => Ok(Self(deque)),
+            false => Ok(Self(Vec::from(deque))),
+        }
+    }
+
+    /// Attempts to create a [NEVec] from an iterator, returning [None] if the iterator yields no
+    /// elements. This stands in for collecting into `Option<NEVec<T>>`, which the orphan rules
+    /// forbid implementing [FromIterator] for.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nev, NEVec};
+    /// #
+    /// assert_eq!(NEVec::try_from_iter(0..0), None);
+    /// assert_eq!(NEVec::try_from_iter(1..4), Some(nev![1, 2, 3]));
+    /// assert_eq!(NEVec::try_from_iter((1..7).filter(|x| x % 2 == 0)), Some(nev![2, 4, 6]));
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Option<Self> {
+        Self::from_vec(Vec::from_iter(iter)).ok()
+    }
+
+    /// Attempts to create a [NEVec] from an iterator yielding at least `max(min, 1)` elements.
+    /// Returns [NonEmptyError::Empty] if the iterator yields nothing, and
+    /// [NonEmptyError::TooShort] if it yields too few elements.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nev, NEVec, NonEmptyError};
+    /// #
+    /// assert_eq!(NEVec::from_iter_min(1..5, 3).unwrap(), nev![1, 2, 3, 4]);
+    /// assert!(matches!(
+    ///     NEVec::from_iter_min(1..3, 3),
+    ///     Err(NonEmptyError::TooShort { needed: 3, got: 2 })
+    /// ));
+    /// assert!(matches!(NEVec::from_iter_min(0..0, 3), Err(NonEmptyError::Empty)));
+    /// ```
+    pub fn from_iter_min<I: IntoIterator<Item = T>>(
+        iter: I,
+        min: usize,
+    ) -> Result<Self, NonEmptyError> {
+        let vec = Vec::from_iter(iter);
+        let needed = min.max(1);
+        match vec.len() {
+            0 => Err(NonEmptyError::Empty),
+            got if got < needed => Err(NonEmptyError::TooShort { needed, got }),
+            _ => Ok(Self(vec)),
         }
     }
 
@@ -130,7 +296,7 @@ impl<T> NEVec<T> {
     {
         match vector.is_empty() {
             true => Err(NonEmptyError::Empty),
-            false => Ok(Self(VecDeque::from_iter(vector.into_iter()))),
+            false => Ok(Self(Vec::from_iter(vector))),
         }
     }
 
@@ -162,6 +328,24 @@ impl<T> NEVec<T> {
         Self::from_vector(vector).unwrap()
     }
 
+    /// Clones the elements of this [NEVec] into a [Vector], preserving order. This is only
+    /// available when the `im` feature is enabled.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3];
+    /// let vector = nev.to_vector();
+    /// assert!(vector.iter().eq(nev.iter()));
+    /// ```
+    #[cfg(feature = "im")]
+    pub fn to_vector(&self) -> Vector<T>
+    where
+        T: Clone,
+    {
+        Vector::from(&self.0[..])
+    }
+
     /// Returns the length of this [NEVec].
     pub fn len(&self) -> usize {
         self.0.len()
@@ -173,30 +357,128 @@ impl<T> NEVec<T> {
     }
 
     /// Returns this [NEVec] as a slice.
-    pub fn as_slice(&mut self) -> &[T] {
-        self.0.make_contiguous();
-        self.0.as_slices().0
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns this [NEVec] as a mutable slice.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3];
+    /// nev.as_mut_slice().iter_mut().for_each(|x| *x *= 2);
+    /// assert!(nev.iter().eq(&[2, 4, 6]));
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.0
     }
 
-    /// Pushes an element to the front of the [NEVec].
+    /// Swaps the contents of the [NEVec] with `other` without reallocating either buffer. Returns
+    /// an error and leaves both untouched if `other` is empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2];
+    /// let mut scratch = vec![3, 4, 5];
+    /// assert!(nev.swap_with_vec(&mut scratch).is_ok());
+    /// assert_eq!(nev, nev![3, 4, 5]);
+    /// assert_eq!(scratch, vec![1, 2]);
+    ///
+    /// assert!(nev.swap_with_vec(&mut Vec::new()).is_err());
+    /// assert_eq!(nev, nev![3, 4, 5]);
+    /// ```
+    pub fn swap_with_vec(&mut self, other: &mut Vec<T>) -> Result<(), NonEmptyError> {
+        if other.is_empty() {
+            return Err(NonEmptyError::Empty);
+        }
+        std::mem::swap(&mut self.0, other);
+        Ok(())
+    }
+
+    /// Pushes an element to the front of the [NEVec]. This is O(n) as every element has to be
+    /// shifted.
     pub fn push_front(&mut self, value: T) {
-        self.0.push_front(value);
+        self.0.insert(0, value);
     }
 
     /// Pushes an element to the back of the [NEVec].
     pub fn push_back(&mut self, value: T) {
-        self.0.push_back(value);
+        self.0.push(value);
+    }
+
+    /// Inserts an element at its sorted position, returning the index it was inserted at. Equal
+    /// elements are inserted after existing ones. The [NEVec] must already be sorted, otherwise
+    /// the position is unspecified. See [NESortedVec](crate::NESortedVec) for a type that keeps
+    /// its elements sorted.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 4, 5];
+    /// assert_eq!(nev.insert_sorted(3), 2);
+    /// assert_eq!(nev, nev![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        let index = self.0.partition_point(|x| *x <= value);
+        self.0.insert(index, value);
+        index
     }
 
-    /// Tries to remove the first element.
+    /// Pushes an element to the front of the [NEVec], ring-buffer style. If the length would
+    /// exceed `cap`, the last element is removed and returned. A `cap` of 0 is treated as 1 so
+    /// the invariant holds.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3];
+    /// assert_eq!(nev.push_front_capped(0, 3), Some(3));
+    /// assert_eq!(nev, nev![0, 1, 2]);
+    /// assert_eq!(nev.push_front_capped(-1, 4), None);
+    /// assert_eq!(nev, nev![-1, 0, 1, 2]);
+    /// ```
+    pub fn push_front_capped(&mut self, value: T, cap: usize) -> Option<T> {
+        self.0.insert(0, value);
+        match self.0.len() > cap.max(1) {
+            true => self.0.pop(),
+            false => None,
+        }
+    }
+
+    /// Like [NEVec::push_front_capped], but rejects the element with
+    /// [NonEmptyError::CapacityExceeded] instead of evicting one when the [NEVec] already holds
+    /// `cap` or more elements.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2];
+    /// assert!(nev.try_push_front_capped(0, 3).is_ok());
+    /// assert!(nev.try_push_front_capped(-1, 3).is_err());
+    /// assert_eq!(nev, nev![0, 1, 2]);
+    /// ```
+    pub fn try_push_front_capped(&mut self, value: T, cap: usize) -> Result<(), NonEmptyError> {
+        match self.0.len() >= cap {
+            true => Err(NonEmptyError::CapacityExceeded),
+            false => {
+                self.0.insert(0, value);
+                Ok(())
+            }
+        }
+    }
+
+    /// Tries to remove the first element. This is O(n) as every remaining element has to be
+    /// shifted.
     pub fn pop_front(&mut self) -> Result<T, NonEmptyError> {
         match self.0.len() {
             0 => Err(NonEmptyError::Empty),
             1 => Err(NonEmptyError::AlreadySingleton),
-            _ => Ok(self
-                .0
-                .pop_front()
-                .expect("[NonEmptyVec] invariant violated.")),
+            _ => Ok(self.0.remove(0)),
         }
     }
 
@@ -205,13 +487,182 @@ impl<T> NEVec<T> {
         match self.0.len() {
             0 => Err(NonEmptyError::Empty),
             1 => Err(NonEmptyError::AlreadySingleton),
-            _ => Ok(self
-                .0
-                .pop_back()
-                .expect("[NonEmptyVec] invariant violated.")),
+            _ => Ok(self.0.pop().expect("[NEVec] invariant violated.")),
+        }
+    }
+
+    /// Like [NEVec::pop_front], but returns [None] instead of an error when the [NEVec] is a
+    /// singleton, leaving it intact.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2];
+    /// assert_eq!(nev.try_pop_front(), Some(1));
+    /// assert_eq!(nev.try_pop_front(), None);
+    /// assert_eq!(nev, nev![2]);
+    /// ```
+    pub fn try_pop_front(&mut self) -> Option<T> {
+        self.pop_front().ok()
+    }
+
+    /// Like [NEVec::pop_back], but returns [None] instead of an error when the [NEVec] is a
+    /// singleton, leaving it intact.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2];
+    /// assert_eq!(nev.try_pop_back(), Some(2));
+    /// assert_eq!(nev.try_pop_back(), None);
+    /// assert_eq!(nev, nev![1]);
+    /// ```
+    pub fn try_pop_back(&mut self) -> Option<T> {
+        self.pop_back().ok()
+    }
+
+    /// Returns `true` if an element can be popped without breaking the invariant, i.e. if the
+    /// [NEVec] is not a singleton.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert!(nev![1, 2].can_pop());
+    /// assert!(!nev![1].can_pop());
+    /// ```
+    pub fn can_pop(&self) -> bool {
+        self.0.len() > 1
+    }
+
+    /// Removes the first element only if more than one element remains. Equivalent to
+    /// [NEVec::try_pop_front], named for use in pop-until-singleton loops.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2];
+    /// assert_eq!(nev.pop_front_if_multiple(), Some(1));
+    /// assert_eq!(nev.pop_front_if_multiple(), None);
+    /// assert_eq!(nev, nev![2]);
+    /// ```
+    pub fn pop_front_if_multiple(&mut self) -> Option<T> {
+        self.try_pop_front()
+    }
+
+    /// Keeps only the last `n` elements, dropping earlier ones. `n` is clamped to at least 1 so
+    /// the invariant holds, and values larger than the length leave the [NEVec] unchanged.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3, 4, 5];
+    /// nev.keep_last(2);
+    /// assert_eq!(nev, nev![4, 5]);
+    /// nev.keep_last(0);
+    /// assert_eq!(nev, nev![5]);
+    /// ```
+    pub fn keep_last(&mut self, n: usize) {
+        let n = n.clamp(1, self.0.len());
+        self.0.drain(..self.0.len() - n);
+    }
+
+    /// Removes the elements in `range`, returning them as an owning iterator. Returns an error
+    /// if `range` covers the whole [NEVec], as that would leave it empty. Panics if `range` is
+    /// out of bounds.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3, 4];
+    /// assert!(nev.drain(1..3).unwrap().eq([2, 3]));
+    /// assert_eq!(nev, nev![1, 4]);
+    /// assert!(nev.drain(0..2).is_err());
+    /// assert_eq!(nev, nev![1, 4]);
+    /// ```
+    pub fn drain(&mut self, range: Range<usize>) -> Result<IntoIter<T>, NonEmptyError> {
+        match range.start == 0 && range.end == self.0.len() {
+            true => Err(NonEmptyError::Empty),
+            false => Ok(Vec::from_iter(self.0.drain(range)).into_iter()),
+        }
+    }
+
+    /// Replaces the elements in `range` with all elements of `replacement`. As `replacement` is
+    /// non-empty, this cannot fail even if `range` covers the whole [NEVec]. Panics if `range` is
+    /// out of bounds.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 3, 4];
+    /// nev.replace_range_ne(1..3, nev![9, 9, 9]);
+    /// assert_eq!(nev, nev![1, 9, 9, 9, 4]);
+    ///
+    /// let len = nev.len();
+    /// nev.replace_range_ne(0..len, nev![7]);
+    /// assert_eq!(nev, nev![7]);
+    /// ```
+    pub fn replace_range_ne(&mut self, range: Range<usize>, replacement: NEVec<T>) {
+        self.0.splice(range, replacement.0);
+    }
+
+    /// Clones the elements in `range` into a new [NEVec]. Returns [NonEmptyError::Empty] if the
+    /// range is empty, and [NonEmptyError::OutOfBounds] if it extends past the end.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::{nev, NonEmptyError};
+    /// #
+    /// let nev = nev![1, 2, 3, 4];
+    /// assert_eq!(nev.subvec(1..3).unwrap(), nev![2, 3]);
+    /// assert!(matches!(nev.subvec(2..2), Err(NonEmptyError::Empty)));
+    /// assert!(matches!(
+    ///     nev.subvec(3..6),
+    ///     Err(NonEmptyError::OutOfBounds { index: 6, len: 4 })
+    /// ));
+    /// assert_eq!(
+    ///     nev.subvec(3..6).unwrap_err().to_string(),
+    ///     "index 6 is out of bounds for a collection of length 4"
+    /// );
+    /// ```
+    pub fn subvec(&self, range: Range<usize>) -> Result<NEVec<T>, NonEmptyError>
+    where
+        T: Clone,
+    {
+        if range.is_empty() {
+            return Err(NonEmptyError::Empty);
+        }
+
+        match self.0.get(range.clone()) {
+            Some(slice) => Ok(NEVec(slice.to_vec())),
+            None => Err(NonEmptyError::OutOfBounds {
+                index: range.end,
+                len: self.0.len(),
+            }),
         }
     }
 
+    /// Clones the leading elements that satisfy `pred` into a new [NEVec]. Returns an error if
+    /// even the head does not satisfy it.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![2, 4, 5, 6];
+    /// assert_eq!(nev.take_while(|x| x % 2 == 0).unwrap(), nev![2, 4]);
+    /// assert!(nev.take_while(|x| x % 2 == 1).is_err());
+    /// ```
+    pub fn take_while<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Result<NEVec<T>, NonEmptyError>
+    where
+        T: Clone,
+    {
+        NEVec::from_vec(
+            self.0
+                .iter()
+                .take_while(|value| pred(value))
+                .cloned()
+                .collect(),
+        )
+    }
+
     /// Splits the [NEVec] into the first element and the rest. This operation is guaranteed
     /// to succeed because the invariant guarantees at least one element is present.
     pub fn split_first(&self) -> (&T, Iter<'_, T>) {
@@ -225,70 +676,1421 @@ impl<T> NEVec<T> {
         (self.init(), self.last())
     }
 
+    /// Like [NEVec::split_first], but returns the rest as a possibly-empty slice, matching
+    /// [slice::split_first].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3].split_first_slice(), (&1, &[2, 3][..]));
+    /// assert_eq!(nev![1].split_first_slice(), (&1, &[][..]));
+    /// ```
+    pub fn split_first_slice(&self) -> (&T, &[T]) {
+        (self.head(), &self.0[1..])
+    }
+
+    /// Returns an iterator over the segments separated by elements matching `is_sep`, starting
+    /// from the back. Separators are not included, and segments may be empty, but there is always
+    /// at least one segment.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let segments: Vec<Vec<i32>> = nev![1, 0, 2, 0, 3].rsplit(|x| *x == 0).collect();
+    /// assert_eq!(segments, vec![vec![3], vec![2], vec![1]]);
+    ///
+    /// let segments: Vec<Vec<i32>> = nev![0].rsplit(|x| *x == 0).collect();
+    /// assert_eq!(segments, vec![vec![], vec![]]);
+    /// ```
+    pub fn rsplit<'a, P: FnMut(&T) -> bool + 'a>(
+        &'a self,
+        is_sep: P,
+    ) -> impl Iterator<Item = Vec<T>> + 'a
+    where
+        T: Clone,
+    {
+        self.0.rsplit(is_sep).map(<[T]>::to_vec)
+    }
+
     /// Like [NEVec::split_first], but consumes the [NEVec].
     pub fn take_split_first(self) -> (T, IntoIter<T>) {
         let mut iter = self.0.into_iter();
-        let head = iter.next().expect("[NonEmptyVec] invariant violated.");
+        let head = iter.next().expect("[NEVec] invariant violated.");
         (head, iter)
     }
 
     /// Like [NEVec::split_last], but consumes the [NEVec].
     pub fn take_split_last(self) -> (IntoIter<T>, T) {
         let mut iter = self.0.into_iter();
-        let last = iter.next_back().expect("[NonEmptyVec] invariant violated.");
+        let last = iter.next_back().expect("[NEVec] invariant violated.");
         (iter, last)
     }
 
-    /// Returns an iterator over the elements of the [NEVec].
-    pub fn iter(&self) -> Iter<'_, T> {
-        self.0.iter()
+    /// Consumes the [NEVec], returning the first element and discarding the rest.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3].into_head(), 1);
+    /// ```
+    pub fn into_head(self) -> T {
+        let (head, _) = self.take_split_first();
+        head
     }
 
-    /// Extends the [NEVec] with the elements from another collection.
-    pub fn extend<I: IntoIterator<Item = T>>(&mut self, other: I) {
-        self.0.extend(other);
+    /// Consumes the [NEVec], returning the last element and discarding the rest.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3].into_last(), 3);
+    /// ```
+    pub fn into_last(mut self) -> T {
+        self.0.pop().expect("[NEVec] invariant violated.")
     }
-}
 
-impl<T> From<NEVec<T>> for Vec<T> {
-    fn from(ne: NEVec<T>) -> Self {
-        ne.0.into()
+    /// Consumes the [NEVec], returning its only element if it is a singleton. Otherwise the
+    /// [NEVec] is handed back unchanged, so no elements are lost.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1].into_singleton(), Ok(1));
+    /// assert_eq!(nev![1, 2].into_singleton(), Err(nev![1, 2]));
+    /// ```
+    pub fn into_singleton(mut self) -> Result<T, NEVec<T>> {
+        match self.0.len() {
+            1 => Ok(self.0.pop().expect("[NEVec] invariant violated.")),
+            _ => Err(self),
+        }
     }
-}
 
-impl<T> From<NEVec<T>> for VecDeque<T> {
-    fn from(ne: NEVec<T>) -> Self {
-        ne.0
+    /// Returns an iterator over the elements of the [NEVec].
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.0.iter()
     }
-}
 
-#[cfg(feature = "im")]
-impl<T> From<NEVec<T>> for Vector<T>
-where
-    T: Clone,
-{
-    fn from(ne: NEVec<T>) -> Self {
-        Vector::from_iter(ne.0.into_iter())
+    /// Returns an iterator over the elements from last to first, each paired with its forward
+    /// index.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev!['a', 'b', 'c'];
+    /// let reversed: Vec<_> = nev.iter_rev_enumerated().collect();
+    /// assert_eq!(reversed, vec![(2, &'c'), (1, &'b'), (0, &'a')]);
+    /// ```
+    pub fn iter_rev_enumerated(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.0.iter().enumerate().rev()
     }
-}
-
-impl<T> TryFrom<Vec<T>> for NEVec<T> {
-    type Error = NonEmptyError;
 
-    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
-        NEVec::from_vec(vec)
+    /// Returns an iterator over all overlapping windows of `size` elements, as borrowed
+    /// [NESlice]s. Nothing is yielded if `size` exceeds the length. Panics if `size` is 0.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3];
+    /// let windows: Vec<&[i32]> = nev.windows_ne(2).map(|window| window.as_slice()).collect();
+    /// assert_eq!(windows, vec![&[1, 2][..], &[2, 3]]);
+    /// assert!(nev.windows_ne(2).all(|window| window.head() < window.last()));
+    /// ```
+    pub fn windows_ne(&self, size: usize) -> impl Iterator<Item = NESlice<'_, T>> + '_ {
+        self.0.windows(size).map(NESlice::__from_slice_unsafe)
     }
-}
 
-impl<T> From<(T, Vec<T>)> for NEVec<T> {
-    fn from(value: (T, Vec<T>)) -> Self {
-        let (head, tail) = value;
-        Self::new(head, tail)
+    /// Returns an iterator yielding each element together with its previous and next neighbours,
+    /// if they exist.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3];
+    /// let neighbors: Vec<_> = nev.iter_neighbors().collect();
+    /// assert_eq!(neighbors, vec![
+    ///     (None, &1, Some(&2)),
+    ///     (Some(&1), &2, Some(&3)),
+    ///     (Some(&2), &3, None),
+    /// ]);
+    /// ```
+    pub fn iter_neighbors(&self) -> impl Iterator<Item = (Option<&T>, &T, Option<&T>)> + '_ {
+        self.0.iter().enumerate().map(move |(index, current)| {
+            let prev = index.checked_sub(1).and_then(|prev| self.0.get(prev));
+            (prev, current, self.0.get(index + 1))
+        })
     }
-}
 
-impl<T> From<(T, VecDeque<T>)> for NEVec<T> {
-    fn from(value: (T, VecDeque<T>)) -> Self {
+    /// Maps each element together with its index into a new [NEVec], leaving this one intact.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev!['a', 'b', 'c'];
+    /// assert_eq!(nev.map_ref_indexed(|i, c| (i, *c)), nev![(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// assert_eq!(nev, nev!['a', 'b', 'c']);
+    /// ```
+    pub fn map_ref_indexed<U, F: FnMut(usize, &T) -> U>(&self, mut f: F) -> NEVec<U> {
+        NEVec(
+            self.0
+                .iter()
+                .enumerate()
+                .map(|(index, value)| f(index, value))
+                .collect(),
+        )
+    }
+
+    /// Consumes the [NEVec], mapping each element while threading a state from left to right.
+    /// Returns the final state along with the mapped elements.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// // Pair each element with its index and a running total.
+    /// let (total, mapped) = nev![10, 20, 30].map_accum((0, 0), |(index, sum), x| {
+    ///     ((index + 1, sum + x), (index, sum + x))
+    /// });
+    /// assert_eq!(total, (3, 60));
+    /// assert_eq!(mapped, nev![(0, 10), (1, 30), (2, 60)]);
+    /// ```
+    pub fn map_accum<S, U, F: FnMut(S, T) -> (S, U)>(self, init: S, mut f: F) -> (S, NEVec<U>) {
+        let mut state = init;
+        let mut mapped = Vec::with_capacity(self.0.len());
+        for value in self.0 {
+            let (next, output) = f(state, value);
+            state = next;
+            mapped.push(output);
+        }
+        (state, NEVec(mapped))
+    }
+
+    /// Combines the [NEVec] with another element-wise using `f`, stopping at the end of the
+    /// shorter one. Both inputs are non-empty, so the result is too.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3].zip_with(nev![10, 20], |a, b| a + b), nev![11, 22]);
+    /// ```
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(self, other: NEVec<U>, mut f: F) -> NEVec<V> {
+        NEVec(
+            self.0
+                .into_iter()
+                .zip(other.0)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        )
+    }
+
+    /// Consumes the [NEVec], mapping each element to an iterator and flattening the results.
+    /// Returns an error if every iterator is empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let doubled = nev![1, 2, 3].flat_map(|x| vec![x, x]).unwrap();
+    /// assert_eq!(doubled, nev![1, 1, 2, 2, 3, 3]);
+    /// assert!(nev![1, 2, 3].flat_map(|_| Vec::<i32>::new()).is_err());
+    /// ```
+    pub fn flat_map<U, I: IntoIterator<Item = U>, F: FnMut(T) -> I>(
+        self,
+        f: F,
+    ) -> Result<NEVec<U>, NonEmptyError> {
+        NEVec::from_vec(self.0.into_iter().flat_map(f).collect())
+    }
+
+    /// Splits the elements into two [Vec]s by mapping each to a [Result], collecting the [Ok]s
+    /// and [Err]s separately. Either side may be empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let (even, odd) = nev![1, 2, 3, 4].partition_map(|x| match x % 2 {
+    ///     0 => Ok(x),
+    ///     _ => Err(x),
+    /// });
+    /// assert_eq!(even, vec![2, 4]);
+    /// assert_eq!(odd, vec![1, 3]);
+    /// ```
+    pub fn partition_map<L, R, F: FnMut(T) -> Result<L, R>>(self, mut f: F) -> (Vec<L>, Vec<R>) {
+        let mut lefts = Vec::new();
+        let mut rights = Vec::new();
+        for value in self.0 {
+            match f(value) {
+                Ok(left) => lefts.push(left),
+                Err(right) => rights.push(right),
+            }
+        }
+        (lefts, rights)
+    }
+
+    /// Extends the [NEVec] with the elements from another collection.
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.0.extend(other);
+    }
+
+    /// Compares the [NEVec] element-by-element against an iterator without collecting it. Both
+    /// must have the same length to be equal.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3];
+    /// assert!(nev.eq_iter(1..4));
+    /// assert!(!nev.eq_iter(1..3));
+    /// assert!(!nev.eq_iter(1..5));
+    /// ```
+    pub fn eq_iter<I: IntoIterator<Item = T>>(&self, other: I) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut other = other.into_iter();
+        self.0
+            .iter()
+            .all(|value| matches!(other.next(), Some(x) if *value == x))
+            && other.next().is_none()
+    }
+
+    /// Returns `true` if `prefix` is a prefix of the [NEVec]. An empty `prefix` always matches.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3, 4];
+    /// assert!(nev.starts_with(&[1, 2]));
+    /// assert!(!nev.starts_with(&[2, 3]));
+    /// assert!(nev.starts_with(&[]));
+    /// ```
+    pub fn starts_with(&self, prefix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.starts_with(prefix)
+    }
+
+    /// Returns `true` if `suffix` is a suffix of the [NEVec]. An empty `suffix` always matches.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3, 4];
+    /// assert!(nev.ends_with(&[3, 4]));
+    /// assert!(!nev.ends_with(&[2, 3]));
+    /// assert!(nev.ends_with(&[]));
+    /// ```
+    pub fn ends_with(&self, suffix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.ends_with(suffix)
+    }
+
+    /// Consumes the [NEVec], appending every element of `iter`. The result is non-empty even if
+    /// `iter` yields nothing.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2].chain(0..0), nev![1, 2]);
+    /// assert_eq!(nev![1, 2].chain(3..5), nev![1, 2, 3, 4]);
+    /// ```
+    pub fn chain<I: IntoIterator<Item = T>>(mut self, iter: I) -> NEVec<T> {
+        self.0.extend(iter);
+        self
+    }
+
+    /// Moves all elements of the [NEVec] onto the end of `target`.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut target = vec![0];
+    /// nev![1, 2].extend_into(&mut target);
+    /// assert_eq!(target, vec![0, 1, 2]);
+    /// ```
+    pub fn extend_into(mut self, target: &mut Vec<T>) {
+        target.append(&mut self.0);
+    }
+
+    /// Returns the starting index of the first occurrence of `needle` as a contiguous run of
+    /// elements, or [None] if it does not occur. An empty `needle` is always found at index 0.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3, 4];
+    /// assert_eq!(nev.find_subslice(&[2, 3]), Some(1));
+    /// assert_eq!(nev.find_subslice(&[3, 2]), None);
+    /// assert_eq!(nev.find_subslice(&[]), Some(0));
+    /// ```
+    pub fn find_subslice(&self, needle: &[T]) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        self.0
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    /// Removes consecutive repeated elements, keeping the last element of each run. At least one
+    /// element always survives, so the invariant holds.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 1, 2, 3, 3];
+    /// nev.dedup_keep_last();
+    /// assert_eq!(nev, nev![1, 2, 3]);
+    /// ```
+    pub fn dedup_keep_last(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut deduped = Vec::with_capacity(self.0.len());
+        for value in std::mem::take(&mut self.0) {
+            match deduped.last_mut() {
+                Some(last) if *last == value => *last = value,
+                _ => deduped.push(value),
+            }
+        }
+        self.0 = deduped;
+    }
+
+    /// Removes consecutive repeated elements, keeping the first element of each run, and returns
+    /// how many elements were removed. At least one element always survives.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 1, 1, 2];
+    /// assert_eq!(nev.dedup_counting(), 2);
+    /// assert_eq!(nev, nev![1, 2]);
+    /// ```
+    pub fn dedup_counting(&mut self) -> usize
+    where
+        T: PartialEq,
+    {
+        let before = self.0.len();
+        self.0.dedup();
+        before - self.0.len()
+    }
+
+    /// Returns the head and every element that differs from its predecessor, collapsing runs of
+    /// equal elements. The head is always kept, so the result is non-empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 1, 2, 2, 3, 1].transitions(), nev![1, 2, 3, 1]);
+    /// ```
+    pub fn transitions(&self) -> NEVec<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut transitions = vec![self.head().clone()];
+        for pair in self.0.windows(2) {
+            if pair[0] != pair[1] {
+                transitions.push(pair[1].clone());
+            }
+        }
+        NEVec(transitions)
+    }
+
+    /// Applies `f` to every pair of adjacent elements. A singleton has no pairs, so the result may
+    /// be empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 3, 6, 10].map_adjacent(|a, b| b - a), vec![2, 3, 4]);
+    /// assert_eq!(nev![1].map_adjacent(|a, b| b - a), vec![]);
+    /// ```
+    pub fn map_adjacent<U, F: FnMut(&T, &T) -> U>(&self, mut f: F) -> Vec<U> {
+        self.0
+            .windows(2)
+            .map(|pair| f(&pair[0], &pair[1]))
+            .collect()
+    }
+
+    /// Removes consecutive elements that `eq` considers equivalent, keeping the first element of
+    /// each run. Each element is compared against the last element kept. At least one element
+    /// always survives.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1.0, 1.0001, 2.0];
+    /// nev.dedup_by_eq(|a: &f64, b: &f64| (a - b).abs() < 0.01);
+    /// assert_eq!(nev, nev![1.0, 2.0]);
+    /// ```
+    pub fn dedup_by_eq<F: FnMut(&T, &T) -> bool>(&mut self, mut eq: F) {
+        self.0.dedup_by(|current, kept| eq(kept, current));
+    }
+
+    /// Like [NEVec::dedup_keep_last], but elements are considered equal when their keys are.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e')];
+    /// nev.dedup_keep_last_by_key(|&(key, _)| key);
+    /// assert_eq!(nev, nev![(1, 'b'), (2, 'c'), (3, 'e')]);
+    /// ```
+    pub fn dedup_keep_last_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        let mut deduped = Vec::with_capacity(self.0.len());
+        let mut last_key = None;
+        for value in std::mem::take(&mut self.0) {
+            let current = key(&value);
+            match deduped.last_mut() {
+                Some(last) if last_key.as_ref() == Some(&current) => *last = value,
+                _ => deduped.push(value),
+            }
+            last_key = Some(current);
+        }
+        self.0 = deduped;
+    }
+
+    /// Sorts the [NEVec] and removes all duplicates, giving the distinct elements in ascending
+    /// order. At least one element always survives, so the result is non-empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![3, 1, 2, 1, 3].sorted_unique(), nev![1, 2, 3]);
+    /// ```
+    pub fn sorted_unique(self) -> NEVec<T>
+    where
+        T: Ord,
+    {
+        let mut vec = self.0;
+        vec.sort();
+        vec.dedup();
+        Self(vec)
+    }
+
+    /// Sorts the [NEVec] by `key` and keeps only the first element for each distinct key. The sort
+    /// is stable, so "first" refers to the original order among elements sharing a key.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// #[derive(Debug, PartialEq)]
+    /// struct User { id: u32, name: &'static str }
+    ///
+    /// let users = nev![
+    ///     User { id: 2, name: "bob" },
+    ///     User { id: 1, name: "ann" },
+    ///     User { id: 2, name: "bea" },
+    /// ];
+    /// let unique = users.sorted_unique_by_key(|user| user.id);
+    /// assert_eq!(unique, nev![User { id: 1, name: "ann" }, User { id: 2, name: "bob" }]);
+    /// ```
+    pub fn sorted_unique_by_key<K: Ord, F: FnMut(&T) -> K>(self, mut key: F) -> NEVec<T> {
+        let mut vec = self.0;
+        vec.sort_by_key(&mut key);
+        vec.dedup_by(|a, b| key(a) == key(b));
+        Self(vec)
+    }
+
+    /// Removes every element equal to an earlier one, wherever it appears, and returns how many
+    /// were removed. The first element always survives.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let mut nev = nev![1, 2, 1, 3, 2];
+    /// assert_eq!(nev.unique_counting(), 2);
+    /// assert_eq!(nev, nev![1, 2, 3]);
+    /// ```
+    pub fn unique_counting(&mut self) -> usize
+    where
+        T: Eq + Hash + Clone,
+    {
+        let before = self.0.len();
+        let mut seen = HashSet::with_capacity(before);
+        self.0.retain(|value| seen.insert(value.clone()));
+        before - self.0.len()
+    }
+
+    /// Returns the distinct elements in first-seen order, each paired with how many times it
+    /// occurs anywhere in the [NEVec].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let counts = nev![1, 2, 1, 3, 2, 1].unique_with_counts();
+    /// assert_eq!(counts, nev![(1, 3), (2, 2), (3, 1)]);
+    /// ```
+    pub fn unique_with_counts(self) -> NEVec<(T, usize)>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut indices: HashMap<T, usize> = HashMap::new();
+        let mut counts: Vec<(T, usize)> = Vec::new();
+        for value in self.0 {
+            match indices.get(&value) {
+                Some(&index) => counts[index].1 += 1,
+                None => {
+                    indices.insert(value.clone(), counts.len());
+                    counts.push((value, 1));
+                }
+            }
+        }
+        NEVec(counts)
+    }
+
+    /// Removes every element equal to an earlier one, wherever it appears, returning the
+    /// remaining elements along with each removed element and its original index. The first
+    /// element always survives, so the result is non-empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let (unique, removed) = nev![1, 2, 1, 3].unique_with_removed();
+    /// assert_eq!(unique, nev![1, 2, 3]);
+    /// assert_eq!(removed, vec![(2, 1)]);
+    /// ```
+    pub fn unique_with_removed(self) -> (NEVec<T>, Vec<(usize, T)>)
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut seen = HashSet::with_capacity(self.0.len());
+        let mut unique = Vec::new();
+        let mut removed = Vec::new();
+        for (index, value) in self.0.into_iter().enumerate() {
+            match seen.insert(value.clone()) {
+                true => unique.push(value),
+                false => removed.push((index, value)),
+            }
+        }
+        (NEVec(unique), removed)
+    }
+
+    /// Returns the distinct elements in ascending order, each paired with how many times it
+    /// occurs in the [NEVec].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let counts = nev![3, 1, 2, 1, 3, 3].sorted_counts();
+    /// assert_eq!(counts, nev![(1, 2), (2, 1), (3, 3)]);
+    /// ```
+    pub fn sorted_counts(self) -> NEVec<(T, usize)>
+    where
+        T: Ord,
+    {
+        let mut vec = self.0;
+        vec.sort();
+        let mut counts: Vec<(T, usize)> = Vec::new();
+        for value in vec {
+            match counts.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => counts.push((value, 1)),
+            }
+        }
+        NEVec(counts)
+    }
+
+    /// Keeps only the first element for each distinct key, preserving first-seen order. Unlike a
+    /// consecutive dedup, duplicates are removed regardless of where they appear. The first
+    /// element always survives, so the result is non-empty.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3, 4, 5, 6];
+    /// assert_eq!(nev.unique_by_key(|x| x % 3), nev![1, 2, 3]);
+    /// ```
+    pub fn unique_by_key<K: Eq + Hash, F: FnMut(&T) -> K>(self, mut key: F) -> NEVec<T> {
+        let mut seen = HashSet::new();
+        let mut vec = self.0;
+        vec.retain(|value| seen.insert(key(value)));
+        Self(vec)
+    }
+
+    /// Returns the index of and a reference to the first element that equals an earlier one, or
+    /// [None] if every element is distinct.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3, 2, 1].first_duplicate(), Some((3, &2)));
+    /// assert_eq!(nev![1, 2, 3].first_duplicate(), None);
+    /// ```
+    pub fn first_duplicate(&self) -> Option<(usize, &T)>
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::with_capacity(self.0.len());
+        self.0
+            .iter()
+            .enumerate()
+            .find(|(_, value)| !seen.insert(*value))
+    }
+
+    /// Interns the elements of the [NEVec], returning the distinct values in first-seen order
+    /// along with, for each original position, the index of its value among the distinct ones.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let (distinct, indices) = nev!['a', 'b', 'a', 'c'].intern();
+    /// assert_eq!(distinct, nev!['a', 'b', 'c']);
+    /// assert_eq!(indices, vec![0, 1, 0, 2]);
+    /// ```
+    pub fn intern(self) -> (NEVec<T>, Vec<usize>)
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut ids: HashMap<T, usize> = HashMap::new();
+        let mut distinct = Vec::new();
+        let indices = self
+            .0
+            .into_iter()
+            .map(|value| {
+                *ids.entry(value).or_insert_with_key(|value| {
+                    distinct.push(value.clone());
+                    distinct.len() - 1
+                })
+            })
+            .collect();
+        (NEVec(distinct), indices)
+    }
+
+    /// Inserts `sep_fn(prev, next)` between every pair of adjacent elements.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![2, 4, 8];
+    /// assert_eq!(nev.join_with(|a, b| (a + b) / 2), nev![2, 3, 4, 6, 8]);
+    /// ```
+    pub fn join_with<F: FnMut(&T, &T) -> T>(self, mut sep_fn: F) -> NEVec<T> {
+        let mut joined = Vec::with_capacity(2 * self.0.len() - 1);
+        let (mut prev, tail) = self.take_split_first();
+        for next in tail {
+            let sep = sep_fn(&prev, &next);
+            joined.push(std::mem::replace(&mut prev, next));
+            joined.push(sep);
+        }
+        joined.push(prev);
+        Self(joined)
+    }
+
+    /// Groups consecutive elements with equal keys into runs. Every run is non-empty, and there
+    /// is always at least one run.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let runs = nev![1, 3, 2, 4, 5].chunk_by_key(|x| x % 2);
+    /// assert_eq!(runs, nev![nev![1, 3], nev![2, 4], nev![5]]);
+    /// ```
+    pub fn chunk_by_key<K: PartialEq, F: FnMut(&T) -> K>(self, mut key: F) -> NEVec<NEVec<T>> {
+        let mut runs: Vec<NEVec<T>> = Vec::new();
+        let mut last_key = None;
+        for value in self.0 {
+            let current = key(&value);
+            match runs.last_mut() {
+                Some(run) if last_key.as_ref() == Some(&current) => run.push_back(value),
+                _ => runs.push(NEVec::singleton(value)),
+            }
+            last_key = Some(current);
+        }
+        NEVec(runs)
+    }
+
+    /// Like [NEVec::chunk_by_key], but pairs every run with the key shared by its elements.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let runs = nev![1, 3, 2, 4].chunk_by_key_tagged(|x| x % 2 == 0);
+    /// assert_eq!(runs, nev![(false, nev![1, 3]), (true, nev![2, 4])]);
+    /// ```
+    pub fn chunk_by_key_tagged<K: PartialEq, F: FnMut(&T) -> K>(
+        self,
+        mut key: F,
+    ) -> NEVec<(K, NEVec<T>)> {
+        let mut runs: Vec<(K, NEVec<T>)> = Vec::new();
+        for value in self.0 {
+            let current = key(&value);
+            match runs.last_mut() {
+                Some((last_key, run)) if *last_key == current => run.push_back(value),
+                _ => runs.push((current, NEVec::singleton(value))),
+            }
+        }
+        NEVec(runs)
+    }
+
+    /// Partitions the [NEVec] into maximal runs of equal consecutive elements. Every run is
+    /// non-empty, and there is always at least one run.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let runs = nev![1, 1, 2, 3, 3, 3].group_runs();
+    /// assert_eq!(runs, nev![nev![1, 1], nev![2], nev![3, 3, 3]]);
+    /// ```
+    pub fn group_runs(self) -> NEVec<NEVec<T>>
+    where
+        T: PartialEq,
+    {
+        let mut runs: Vec<NEVec<T>> = Vec::new();
+        for value in self.0 {
+            match runs.last_mut() {
+                Some(run) if *run.last() == value => run.push_back(value),
+                _ => runs.push(NEVec::singleton(value)),
+            }
+        }
+        NEVec(runs)
+    }
+
+    /// Returns the lengths of the maximal runs of equal consecutive elements, as
+    /// [NEVec::group_runs] would group them. There is always at least one run.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 1, 2, 3, 3, 3].run_lengths(), nev![2, 1, 3]);
+    /// ```
+    pub fn run_lengths(&self) -> NEVec<usize>
+    where
+        T: PartialEq,
+    {
+        let mut lengths = Vec::new();
+        let mut current = 1;
+        for pair in self.0.windows(2) {
+            match pair[0] == pair[1] {
+                true => current += 1,
+                false => lengths.push(std::mem::replace(&mut current, 1)),
+            }
+        }
+        lengths.push(current);
+        NEVec(lengths)
+    }
+
+    /// Consumes the [NEVec], collecting its distinct elements into a [NEOrderedSet].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let set = nev![3, 1, 2, 1].into_ordered_set();
+    /// assert!(set.into_iter().eq([1, 2, 3]));
+    /// ```
+    pub fn into_ordered_set(self) -> NEOrderedSet<T>
+    where
+        T: Ord,
+    {
+        NEOrderedSet::__from_set_unsafe(BTreeSet::from_iter(self.0))
+    }
+
+    /// Returns every pair of an element of this [NEVec] with an element of `other`, in
+    /// row-major order. As both vectors are non-empty, so is the product.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let product = nev![1, 2].cartesian_product(&nev!['a', 'b']);
+    /// assert_eq!(product, nev![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    /// ```
+    pub fn cartesian_product<U: Clone>(&self, other: &NEVec<U>) -> NEVec<(T, U)>
+    where
+        T: Clone,
+    {
+        let mut product = Vec::with_capacity(self.0.len() * other.0.len());
+        for left in &self.0 {
+            product.extend(other.0.iter().map(|right| (left.clone(), right.clone())));
+        }
+        NEVec(product)
+    }
+
+    /// Returns an iterator over every permutation of the [NEVec], starting with the identity.
+    /// Elements are permuted by position, so equal elements still yield `n!` permutations. As
+    /// `n!` grows very quickly, this is only intended for small inputs.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3];
+    /// let permutations: Vec<_> = nev.permutations().collect();
+    /// assert_eq!(permutations.len(), 6);
+    /// assert_eq!(permutations[0], nev);
+    /// assert_eq!(permutations[5], nev![3, 2, 1]);
+    /// assert!(permutations.iter().all(|permutation| permutation.len() == 3));
+    /// ```
+    pub fn permutations(&self) -> impl Iterator<Item = NEVec<T>> + '_
+    where
+        T: Clone,
+    {
+        let mut indices: Vec<usize> = (0..self.0.len()).collect();
+        let mut exhausted = false;
+        std::iter::from_fn(move || {
+            if exhausted {
+                return None;
+            }
+
+            let permutation = NEVec(indices.iter().map(|&i| self.0[i].clone()).collect());
+
+            // Advance the indices to their next permutation in lexicographic order.
+            match indices.windows(2).rposition(|pair| pair[0] < pair[1]) {
+                Some(pivot) => {
+                    let successor = indices
+                        .iter()
+                        .rposition(|&i| i > indices[pivot])
+                        .expect("[NEVec] the element after the pivot should be larger than it.");
+                    indices.swap(pivot, successor);
+                    indices[pivot + 1..].reverse();
+                }
+                None => exhausted = true,
+            }
+
+            Some(permutation)
+        })
+    }
+
+    /// Returns an iterator over borrowed chunks of `size` elements, without cloning. The final
+    /// chunk may be shorter. Panics if `size` is 0.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3, 4, 5];
+    /// let chunks: Vec<&[i32]> = nev.chunks_ref(2).collect();
+    /// assert_eq!(chunks, vec![&[1, 2][..], &[3, 4], &[5]]);
+    /// ```
+    pub fn chunks_ref(&self, size: usize) -> Chunks<'_, T> {
+        self.0.chunks(size)
+    }
+
+    /// Returns an iterator over chunks of `size` borrowed elements through a shared reference.
+    /// Every chunk is non-empty, and the final chunk may be shorter. Panics if `size` is 0.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = &nev![1, 2, 3, 4, 5];
+    /// let chunks: Vec<Vec<&i32>> = nev.iter_chunks(2).collect();
+    /// assert_eq!(chunks, vec![vec![&1, &2], vec![&3, &4], vec![&5]]);
+    /// ```
+    pub fn iter_chunks(&self, size: usize) -> impl Iterator<Item = Vec<&T>> + '_ {
+        self.0.chunks(size).map(|chunk| chunk.iter().collect())
+    }
+
+    /// Like [NEVec::iter_chunks], but takes a [NonZeroUsize] so the size can never be 0 and the
+    /// call cannot panic.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// # use std::num::NonZeroUsize;
+    /// #
+    /// let nev = nev![1, 2, 3, 4, 5];
+    /// let chunks: Vec<Vec<&i32>> = nev.chunks_nonzero(NonZeroUsize::new(2).unwrap()).collect();
+    /// assert_eq!(chunks, vec![vec![&1, &2], vec![&3, &4], vec![&5]]);
+    /// ```
+    pub fn chunks_nonzero(&self, size: NonZeroUsize) -> impl Iterator<Item = Vec<&T>> + '_ {
+        self.iter_chunks(size.get())
+    }
+
+    /// Maps each chunk of `size` elements to a single value. The final chunk may be shorter.
+    /// There is always at least one chunk, so the result is non-empty. Panics if `size` is 0.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1, 2, 3, 4, 5];
+    /// assert_eq!(nev.map_chunks(2, |chunk| chunk.iter().sum::<i32>()), nev![3, 7, 5]);
+    /// ```
+    pub fn map_chunks<U, F: FnMut(&[T]) -> U>(&self, size: usize, f: F) -> NEVec<U> {
+        NEVec(self.0.chunks(size).map(f).collect())
+    }
+
+    /// Returns the index of the element with the maximum key. Like [Iterator::max_by_key], the
+    /// last maximum wins on ties. This cannot fail as the invariant guarantees at least one
+    /// element is present.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// struct Player { name: &'static str, score: u32 }
+    ///
+    /// let players = nev![
+    ///     Player { name: "ada", score: 3 },
+    ///     Player { name: "bob", score: 7 },
+    ///     Player { name: "cyd", score: 7 },
+    ///     Player { name: "dee", score: 1 },
+    /// ];
+    /// let best = players.position_max_by_key(|player| player.score);
+    /// assert_eq!(players[best].name, "cyd");
+    /// ```
+    pub fn position_max_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut key: F) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, value)| key(value))
+            .map(|(index, _)| index)
+            .expect("[NEVec] invariant violated.")
+    }
+
+    /// Returns the index of the element with the minimum key. Like [Iterator::min_by_key], the
+    /// first minimum wins on ties. This cannot fail as the invariant guarantees at least one
+    /// element is present.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// struct Player { name: &'static str, score: u32 }
+    ///
+    /// let players = nev![
+    ///     Player { name: "ada", score: 3 },
+    ///     Player { name: "bob", score: 1 },
+    ///     Player { name: "cyd", score: 1 },
+    /// ];
+    /// let worst = players.position_min_by_key(|player| player.score);
+    /// assert_eq!(players[worst].name, "bob");
+    /// ```
+    pub fn position_min_by_key<K: Ord, F: FnMut(&T) -> K>(&self, mut key: F) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, value)| key(value))
+            .map(|(index, _)| index)
+            .expect("[NEVec] invariant violated.")
+    }
+
+    /// Returns the maximum element with respect to `compare`. Like [Iterator::max_by], the last
+    /// maximum wins on ties. This cannot fail as the invariant guarantees at least one element is
+    /// present.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1.0, 3.5, 2.2];
+    /// assert_eq!(*nev.max_by(|a, b| a.partial_cmp(b).unwrap()), 3.5);
+    /// ```
+    pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+        self.0
+            .iter()
+            .max_by(|a, b| compare(a, b))
+            .expect("[NEVec] invariant violated.")
+    }
+
+    /// Returns the minimum element with respect to `compare`. Like [Iterator::min_by], the first
+    /// minimum wins on ties. This cannot fail as the invariant guarantees at least one element is
+    /// present.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let nev = nev![1.0, 3.5, 2.2];
+    /// assert_eq!(*nev.min_by(|a, b| a.partial_cmp(b).unwrap()), 1.0);
+    /// ```
+    pub fn min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+        self.0
+            .iter()
+            .min_by(|a, b| compare(a, b))
+            .expect("[NEVec] invariant violated.")
+    }
+
+    /// Returns the arithmetic mean of the elements. This cannot divide by zero as the invariant
+    /// guarantees at least one element is present.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3, 4].mean(), 2.5);
+    /// ```
+    pub fn mean(&self) -> f64
+    where
+        T: Into<f64> + Copy,
+    {
+        let sum: f64 = self.0.iter().map(|&value| value.into()).sum();
+        sum / self.0.len() as f64
+    }
+
+    /// Returns the median element. For an even length there are two middle elements, and the
+    /// lower one is returned. This cannot fail as the invariant guarantees at least one element
+    /// is present. The [NEVec] itself is left unchanged.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![3, 1, 2].median(), 2);
+    /// assert_eq!(nev![4, 1, 3, 2].median(), 2);
+    /// ```
+    pub fn median(&self) -> T
+    where
+        T: Ord + Clone,
+    {
+        let mut vec = self.0.clone();
+        let middle = (vec.len() - 1) / 2;
+        let (_, median, _) = vec.select_nth_unstable(middle);
+        median.clone()
+    }
+
+    /// Reduces the [NEVec] from the back, seeded by the last element, computing
+    /// `f(x0, f(x1, ... f(xn-2, xn-1)))`. This cannot fail as the invariant guarantees at least
+    /// one element is present.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3, 4].rreduce(|a, b| a - b), 1 - (2 - (3 - 4)));
+    /// assert_eq!(nev![2, 3, 4].rreduce(|a, b| a * 10 + b), 54);
+    /// assert_eq!(nev![7].rreduce(|a, b| a - b), 7);
+    /// ```
+    pub fn rreduce<F: FnMut(T, T) -> T>(self, mut f: F) -> T {
+        let (init, last) = self.take_split_last();
+        init.rfold(last, |acc, value| f(value, acc))
+    }
+
+    /// Returns every intermediate state of folding `f` over the elements, starting with `init`
+    /// itself. The result has one more element than the [NEVec].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 2, 3].prefix_scan(0, |sum, x| sum + x), nev![0, 1, 3, 6]);
+    /// ```
+    pub fn prefix_scan<U, F: FnMut(&U, &T) -> U>(&self, init: U, mut f: F) -> NEVec<U> {
+        let mut states = Vec::with_capacity(self.0.len() + 1);
+        let mut state = init;
+        for value in &self.0 {
+            let next = f(&state, value);
+            states.push(std::mem::replace(&mut state, next));
+        }
+        states.push(state);
+        NEVec(states)
+    }
+
+    /// Folds over every window of `window` consecutive elements, carrying a state from one
+    /// window to the next and collecting the state after each window. There is one state per
+    /// window position, so the result is non-empty. Panics if `window` is 0 or greater than the
+    /// length of the [NEVec].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// // Exponentially smooth the averages of each pair of readings.
+    /// let readings = nev![2.0, 4.0, 6.0, 10.0];
+    /// let smoothed = readings.sliding_fold(2, 0.0, |state, window| {
+    ///     let average = window.iter().sum::<f64>() / window.len() as f64;
+    ///     (state + average) / 2.0
+    /// });
+    /// assert_eq!(smoothed, nev![1.5, 3.25, 5.625]);
+    /// ```
+    pub fn sliding_fold<S, F: FnMut(&S, &[T]) -> S>(
+        &self,
+        window: usize,
+        init: S,
+        mut f: F,
+    ) -> NEVec<S> {
+        assert!(
+            window <= self.0.len(),
+            "window size {} exceeds length {}",
+            window,
+            self.0.len()
+        );
+
+        let mut windows = self.0.windows(window);
+        let first = windows
+            .next()
+            .expect("[NEVec] a window no longer than the vector should exist.");
+        let mut states = Vec::with_capacity(self.0.len() - window + 1);
+        let mut state = f(&init, first);
+        for current in windows {
+            let next = f(&state, current);
+            states.push(std::mem::replace(&mut state, next));
+        }
+        states.push(state);
+        NEVec(states)
+    }
+
+    /// Returns the maximum of every window of `window` consecutive elements, in O(n) time. There
+    /// is one maximum per window position, so the result is non-empty. Panics if `window` is 0 or
+    /// greater than the length of the [NEVec].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 3, 2, 5, 4].sliding_max(2), nev![3, 3, 5, 5]);
+    /// assert_eq!(nev![1, 3, 2, 5, 4].sliding_max(5), nev![5]);
+    /// ```
+    pub fn sliding_max(&self, window: usize) -> NEVec<T>
+    where
+        T: Ord + Clone,
+    {
+        self.sliding_extremum(window, |value, kept| value >= kept)
+    }
+
+    /// Returns the minimum of every window of `window` consecutive elements, in O(n) time. There
+    /// is one minimum per window position, so the result is non-empty. Panics if `window` is 0 or
+    /// greater than the length of the [NEVec].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![1, 3, 2, 5, 4].sliding_min(2), nev![1, 2, 2, 4]);
+    /// assert_eq!(nev![1, 3, 2, 5, 4].sliding_min(1), nev![1, 3, 2, 5, 4]);
+    /// ```
+    pub fn sliding_min(&self, window: usize) -> NEVec<T>
+    where
+        T: Ord + Clone,
+    {
+        self.sliding_extremum(window, |value, kept| value <= kept)
+    }
+
+    /// Shared monotonic-deque implementation of [NEVec::sliding_max] and [NEVec::sliding_min].
+    /// The deque holds indices of the current window whose values no later element `dominates`,
+    /// so its front is always the extremum of the window.
+    fn sliding_extremum<F: Fn(&T, &T) -> bool>(&self, window: usize, dominates: F) -> NEVec<T>
+    where
+        T: Clone,
+    {
+        assert!(
+            window != 0 && window <= self.0.len(),
+            "window size {} must be between 1 and length {}",
+            window,
+            self.0.len()
+        );
+
+        let mut deque: VecDeque<usize> = VecDeque::with_capacity(window);
+        let mut extrema = Vec::with_capacity(self.0.len() - window + 1);
+        for (index, value) in self.0.iter().enumerate() {
+            while deque
+                .back()
+                .map_or(false, |&kept| dominates(value, &self.0[kept]))
+            {
+                deque.pop_back();
+            }
+            deque.push_back(index);
+            if index >= window && deque.front() == Some(&(index - window)) {
+                deque.pop_front();
+            }
+            if index + 1 >= window {
+                let front = deque
+                    .front()
+                    .expect("[NEVec] the deque should hold the index just pushed.");
+                extrema.push(self.0[*front].clone());
+            }
+        }
+        NEVec(extrema)
+    }
+
+    /// Shuffles the elements of the [NEVec] in place. The length is preserved, so the invariant
+    /// holds. This is only available when the `rand` feature is enabled.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// # use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+    /// #
+    /// let mut nev = nev![1, 2, 3, 4, 5];
+    /// nev.shuffle(&mut StdRng::seed_from_u64(42));
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    /// vec.shuffle(&mut StdRng::seed_from_u64(42));
+    /// assert_eq!(Vec::from(nev), vec);
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn shuffle<R: Rng>(&mut self, rng: &mut R) {
+        self.0.shuffle(rng);
+    }
+
+    /// Returns a reference to a uniformly random element. Unlike choosing from a slice, this
+    /// cannot fail as the invariant guarantees at least one element is present. This is only
+    /// available when the `rand` feature is enabled.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// # use rand::{rngs::StdRng, SeedableRng};
+    /// #
+    /// let nev = nev![1, 2, 3, 4, 5];
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// for _ in 0..100 {
+    ///     let chosen = nev.choose(&mut rng);
+    ///     assert!(nev.iter().any(|x| x == chosen));
+    /// }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn choose<R: Rng>(&self, rng: &mut R) -> &T {
+        &self.0[rng.random_range(0..self.0.len())]
+    }
+
+    /// Maps every element in parallel with a fallible function. Returns the error of the earliest
+    /// failing element, by position, if any. This is only available when the `rayon` feature is
+    /// enabled.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let parse = |s: &&str| s.parse::<i32>().map_err(|_| s.to_string());
+    /// assert_eq!(nev!["1", "2", "3"].try_map_par(parse), Ok(nev![1, 2, 3]));
+    /// assert_eq!(nev!["1", "x", "y"].try_map_par(parse), Err("x".to_string()));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn try_map_par<U: Send, E: Send, F: Fn(&T) -> Result<U, E> + Sync>(
+        &self,
+        f: F,
+    ) -> Result<NEVec<U>, E>
+    where
+        T: Sync,
+    {
+        // Collecting straight into a `Result` would report whichever error a thread hit first,
+        // so gather every outcome in order and pick the earliest error sequentially.
+        let results: Vec<Result<U, E>> = self.0.par_iter().map(&f).collect();
+        results.into_iter().collect::<Result<_, _>>().map(NEVec)
+    }
+}
+
+impl<T> NEVec<NEVec<T>> {
+    /// Transposes a [NEVec] of rows into a [NEVec] of columns. Returns an error if the rows have
+    /// differing lengths. The result is non-empty as both dimensions are.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let matrix = nev![nev![1, 2, 3], nev![4, 5, 6]];
+    /// let transposed = nev![nev![1, 4], nev![2, 5], nev![3, 6]];
+    /// assert_eq!(matrix.transpose().unwrap(), transposed);
+    ///
+    /// let ragged = nev![nev![1, 2, 3], nev![4, 5]];
+    /// assert!(ragged.transpose().is_err());
+    /// ```
+    pub fn transpose(self) -> Result<NEVec<NEVec<T>>, NonEmptyError> {
+        let width = self.head().len();
+        if self.iter().any(|row| row.len() != width) {
+            return Err(NonEmptyError::LengthMismatch);
+        }
+
+        let mut rows: Vec<IntoIter<T>> = self.0.into_iter().map(|row| row.0.into_iter()).collect();
+        let columns = (0..width)
+            .map(|_| {
+                // Every row has exactly `width` elements, so none of them run out early.
+                NEVec(rows.iter_mut().filter_map(Iterator::next).collect())
+            })
+            .collect();
+        Ok(NEVec(columns))
+    }
+}
+
+impl<T, E> NEVec<Result<T, E>> {
+    /// Converts a [NEVec] of [Result]s into a [Result] of a [NEVec], returning the first error
+    /// encountered, if any.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let results = nev![Ok(1), Ok(2), Ok(3)];
+    /// assert_eq!(results.transpose_result(), Ok::<_, &str>(nev![1, 2, 3]));
+    ///
+    /// let results = nev![Ok(1), Err("two"), Ok(3), Err("four")];
+    /// assert_eq!(results.transpose_result(), Err("two"));
+    /// ```
+    pub fn transpose_result(self) -> Result<NEVec<T>, E> {
+        self.0.into_iter().collect::<Result<_, _>>().map(NEVec)
+    }
+}
+
+impl<T> NEVec<Option<T>> {
+    /// Converts a [NEVec] of [Option]s into an [Option] of a [NEVec], returning [None] if any
+    /// element is [None].
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev![Some(1), Some(2), Some(3)].transpose_option(), Some(nev![1, 2, 3]));
+    /// assert_eq!(nev![Some(1), None, Some(3)].transpose_option(), None);
+    /// ```
+    pub fn transpose_option(self) -> Option<NEVec<T>> {
+        self.0.into_iter().collect::<Option<_>>().map(NEVec)
+    }
+}
+
+impl NEVec<String> {
+    /// Joins the segments with `sep`, first passing each one to `validate`. Returns the first
+    /// validation error, if any, in which case no string is built.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// let validate = |segment: &str| if segment.is_empty() { Err("empty segment") } else { Ok(()) };
+    ///
+    /// let segments = nev!["a".to_string(), "b".to_string()];
+    /// assert_eq!(segments.try_join(", ", validate), Ok("a, b".to_string()));
+    ///
+    /// let segments = nev!["a".to_string(), String::new(), "c".to_string()];
+    /// assert_eq!(segments.try_join(", ", validate), Err("empty segment"));
+    /// ```
+    pub fn try_join<E, F: FnMut(&str) -> Result<(), E>>(
+        &self,
+        sep: &str,
+        mut validate: F,
+    ) -> Result<String, E> {
+        self.0.iter().try_for_each(|segment| validate(segment))?;
+        Ok(self.0.join(sep))
+    }
+}
+
+impl NEVec<char> {
+    /// Collects the characters into a [String], in order. The string holds at least one
+    /// character.
+    ///
+    /// ```rust
+    /// # use nonempty_containers::nev;
+    /// #
+    /// assert_eq!(nev!['a', 'b', 'c'].into_string(), "abc");
+    /// ```
+    pub fn into_string(self) -> String {
+        self.0.into_iter().collect()
+    }
+}
+
+impl<T> From<NEVec<T>> for Vec<T> {
+    fn from(ne: NEVec<T>) -> Self {
+        ne.0
+    }
+}
+
+impl<T> From<NEVec<T>> for VecDeque<T> {
+    fn from(ne: NEVec<T>) -> Self {
+        ne.0.into()
+    }
+}
+
+#[cfg(feature = "im")]
+impl<T> From<NEVec<T>> for Vector<T>
+where
+    T: Clone,
+{
+    fn from(ne: NEVec<T>) -> Self {
+        Vector::from_iter(ne.0)
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NEVec<T> {
+    type Error = NonEmptyError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        NEVec::from_vec(vec)
+    }
+}
+
+impl<T> From<(T, Vec<T>)> for NEVec<T> {
+    fn from(value: (T, Vec<T>)) -> Self {
+        let (head, tail) = value;
+        Self::new(head, tail)
+    }
+}
+
+impl<T> From<(T, VecDeque<T>)> for NEVec<T> {
+    fn from(value: (T, VecDeque<T>)) -> Self {
         let (head, tail) = value;
         Self::new(head, Vec::from(tail))
     }
@@ -298,7 +2100,7 @@ impl<T> From<(T, VecDeque<T>)> for NEVec<T> {
 impl<T: Clone> From<(T, Vector<T>)> for NEVec<T> {
     fn from(value: (T, Vector<T>)) -> Self {
         let (head, tail) = value;
-        Self::new(head, Vec::from_iter(tail.into_iter()))
+        Self::new(head, Vec::from_iter(tail))
     }
 }
 
@@ -335,10 +2137,50 @@ impl<T> IntoIterator for NEVec<T> {
     }
 }
 
-impl<T> Index<usize> for NEVec<T> {
-    type Output = T;
+impl<T, I: SliceIndex<[T]>> Index<I> for NEVec<T> {
+    type Output = I::Output;
 
-    fn index(&self, index: usize) -> &Self::Output {
+    fn index(&self, index: I) -> &Self::Output {
         &self.0[index]
     }
 }
+
+/// Mutably indexes the [NEVec] like a slice. Elements can be overwritten in place, but never
+/// removed, so the invariant is preserved.
+///
+/// ```rust
+/// # use nonempty_containers::nev;
+/// #
+/// let mut nev = nev![1, 2, 3];
+/// nev[1] = 99;
+/// nev[..2].reverse();
+/// assert_eq!(nev, nev![99, 1, 3]);
+/// ```
+impl<T, I: SliceIndex<[T]>> IndexMut<I> for NEVec<T> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+/// Borrows the [NEVec] as a slice, so slice methods can be called through a shared reference.
+///
+/// ```rust
+/// # use nonempty_containers::{nev, NEVec};
+/// #
+/// fn sum(slice: &[i32]) -> i32 {
+///     slice.iter().sum()
+/// }
+///
+/// let nev: &NEVec<i32> = &nev![1, 2, 3, 4];
+/// assert_eq!(sum(nev), 10);
+/// assert_eq!(&nev[1..3], &[2, 3]);
+/// assert!(nev.contains(&3));
+/// assert_eq!(nev.as_slice(), &[1, 2, 3, 4]);
+/// ```
+impl<T> Deref for NEVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,76 @@
+//! Implementations for [Serialize] and [Deserialize] for non-empty container types.
+
+use crate::NEVec;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Upper bound on preallocation from an untrusted size hint.
+const MAX_PREALLOCATION: usize = 4096;
+
+/// A [NEVec] serializes as a plain sequence, identically to a [Vec].
+///
+/// ```rust
+/// # use nonempty_containers::{nev, NEVec};
+/// #
+/// let nev: NEVec<u8> = nev![1, 2, 3];
+/// let vec: Vec<u8> = vec![1, 2, 3];
+/// assert_eq!(serde_json::to_vec(&nev).unwrap(), serde_json::to_vec(&vec).unwrap());
+/// assert_eq!(bincode::serialize(&nev).unwrap(), bincode::serialize(&vec).unwrap());
+/// ```
+impl<T: Serialize> Serialize for NEVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter() {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+/// A [NEVec] deserializes from a plain sequence, failing if the sequence is empty.
+///
+/// ```rust
+/// # use nonempty_containers::{nev, NEVec};
+/// #
+/// let nev: NEVec<u8> = serde_json::from_str("[1, 2, 3]").unwrap();
+/// assert_eq!(nev, nev![1, 2, 3]);
+/// assert!(serde_json::from_str::<NEVec<u8>>("[]").is_err());
+///
+/// let bytes = bincode::serialize(&vec![1u8, 2, 3]).unwrap();
+/// assert_eq!(bincode::deserialize::<NEVec<u8>>(&bytes).unwrap(), nev![1, 2, 3]);
+/// let empty = bincode::serialize(&Vec::<u8>::new()).unwrap();
+/// assert!(bincode::deserialize::<NEVec<u8>>(&empty).is_err());
+/// ```
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NEVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(NEVecVisitor(PhantomData))
+    }
+}
+
+/// Visits a sequence, requiring at least one element.
+struct NEVecVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for NEVecVisitor<T> {
+    type Value = NEVec<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a non-empty sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let head = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATION);
+        let mut vec = Vec::with_capacity(1 + capacity);
+        vec.push(head);
+        while let Some(element) = seq.next_element()? {
+            vec.push(element);
+        }
+        Ok(NEVec::__from_vec_unsafe(vec))
+    }
+}